@@ -1,9 +1,7 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId, Throughput};
 use wasm_crypto::*;
-use num_bigint::{BigUint, RandBigInt};
+use num_bigint::BigUint;
 use num_traits::Num;
-use getrandom::getrandom;
-use blst::{blst_scalar, blst_p1, blst_p2};
 use std::time::Duration;
 
 // Mock JavaScript performance for comparison
@@ -23,8 +21,8 @@ fn js_field_multiplication(iterations: u32) -> Duration {
 fn wasm_field_multiplication(iterations: u32) -> Duration {
     let start = std::time::Instant::now();
     
-    let a = FpElement::random();
-    let b = FpElement::random();
+    let a = FpElement::random().unwrap();
+    let b = FpElement::random().unwrap();
     
     for _ in 0..iterations {
         let _ = black_box(a.mul(&b));
@@ -53,8 +51,8 @@ fn js_point_addition(iterations: u32) -> Duration {
 fn wasm_point_addition(iterations: u32) -> Duration {
     let start = std::time::Instant::now();
     
-    let p1 = G1Point::random();
-    let p2 = G1Point::random();
+    let p1 = G1Point::random().unwrap();
+    let p2 = G1Point::random().unwrap();
     
     for _ in 0..iterations {
         let _ = black_box(p1.add(&p2));
@@ -80,7 +78,7 @@ fn js_scalar_multiplication(iterations: u32) -> Duration {
 fn wasm_scalar_multiplication(iterations: u32) -> Duration {
     let start = std::time::Instant::now();
     
-    let p = G1Point::random();
+    let p = G1Point::random().unwrap();
     let scalar = "1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
     
     for _ in 0..iterations {
@@ -195,6 +193,37 @@ fn benchmark_hash_functions(c: &mut Criterion) {
     group.finish();
 }
 
+fn benchmark_hash_algorithms(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Hash Algorithms");
+    
+    let sizes = [64, 1024, 16384];
+    
+    for &n in &sizes {
+        let data = vec![0xabu8; n];
+        group.throughput(Throughput::Bytes(n as u64));
+        
+        group.bench_with_input(
+            BenchmarkId::new("WASM SHA-256", n),
+            &data,
+            |b, data| b.iter(|| black_box(HashFunctions::sha256(data)))
+        );
+        
+        group.bench_with_input(
+            BenchmarkId::new("WASM BLAKE2b", n),
+            &data,
+            |b, data| b.iter(|| black_box(HashFunctions::blake2b(data)))
+        );
+        
+        group.bench_with_input(
+            BenchmarkId::new("WASM BLAKE3", n),
+            &data,
+            |b, data| b.iter(|| black_box(HashFunctions::blake3(data)))
+        );
+    }
+    
+    group.finish();
+}
+
 fn benchmark_pairing_operations(c: &mut Criterion) {
     let mut group = c.benchmark_group("Pairing Operations");
     
@@ -205,8 +234,8 @@ fn benchmark_pairing_operations(c: &mut Criterion) {
             BenchmarkId::new("WASM Pairing", i),
             &i,
             |b, &i| {
-                let g1 = G1Point::random();
-                let g2 = G2Point::random();
+                let g1 = G1Point::random().unwrap();
+                let g2 = G2Point::random().unwrap();
                 
                 b.iter(|| {
                     for _ in 0..i {
@@ -220,16 +249,226 @@ fn benchmark_pairing_operations(c: &mut Criterion) {
     group.finish();
 }
 
+fn benchmark_mul_add(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Double Scalar Multiplication");
+    
+    let p = G1Point::random().unwrap();
+    let q = G1Point::random().unwrap();
+    let s1 = Scalar::random().unwrap();
+    let s2 = Scalar::random().unwrap();
+    
+    group.bench_function("WASM Separate Multiplications", |b| {
+        b.iter(|| black_box(p.scalar_mul_fr(&s1).add(&q.scalar_mul_fr(&s2))))
+    });
+    
+    group.bench_function("WASM Shamir mul_add", |b| {
+        b.iter(|| black_box(p.mul_add(&s1, &q, &s2)))
+    });
+    
+    group.finish();
+}
+
+fn benchmark_fixed_base(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Fixed-Base Multiplication");
+    group.sample_size(20);
+    
+    let n = 1000;
+    let point = G1Point::random().unwrap();
+    let scalars: Vec<Scalar> = (0..n).map(|_| Scalar::random().unwrap()).collect();
+    let precomputed = G1Precomputed::new(&point);
+    
+    group.bench_function(
+        BenchmarkId::new("WASM scalar_mul_fr", n),
+        |b| b.iter(|| {
+            for s in &scalars {
+                black_box(point.scalar_mul_fr(s));
+            }
+        })
+    );
+    
+    group.bench_function(
+        BenchmarkId::new("WASM G1Precomputed", n),
+        |b| b.iter(|| {
+            for s in &scalars {
+                black_box(precomputed.scalar_mul(s));
+            }
+        })
+    );
+    
+    group.bench_function("WASM G1Precomputed setup", |b| {
+        b.iter(|| black_box(G1Precomputed::new(&point)))
+    });
+    
+    group.finish();
+}
+
+fn benchmark_generator_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Generator Multiplication");
+    
+    let scalar = Scalar::random().unwrap();
+    // Build the tables outside the timed loop
+    g1_mul_generator(&scalar);
+    g2_mul_generator(&scalar);
+    
+    group.bench_function("G1 generator().scalar_mul_fr", |b| {
+        b.iter(|| black_box(G1Point::generator().scalar_mul_fr(&scalar)))
+    });
+    
+    group.bench_function("G1 g1_mul_generator", |b| {
+        b.iter(|| black_box(g1_mul_generator(&scalar)))
+    });
+    
+    group.bench_function("G2 generator().scalar_mul_fr", |b| {
+        b.iter(|| black_box(G2Point::generator().scalar_mul_fr(&scalar)))
+    });
+    
+    group.bench_function("G2 g2_mul_generator", |b| {
+        b.iter(|| black_box(g2_mul_generator(&scalar)))
+    });
+    
+    group.finish();
+}
+
+fn benchmark_msm(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Multi-Scalar Multiplication");
+    
+    let sizes = [16, 64, 256];
+    
+    for &n in &sizes {
+        let points: Vec<G1Point> = (0..n).map(|_| G1Point::random().unwrap()).collect();
+        let scalars: Vec<Scalar> = (0..n).map(|_| Scalar::random().unwrap()).collect();
+        let scalar_hex: Vec<String> = scalars.iter().map(|s| s.to_hex()).collect();
+        
+        group.bench_with_input(
+            BenchmarkId::new("WASM Naive MSM", n),
+            &n,
+            |b, _| {
+                b.iter(|| {
+                    let mut acc = G1Point::identity();
+                    for (p, s) in points.iter().zip(&scalar_hex) {
+                        acc = acc.add(&p.scalar_mul(s).unwrap());
+                    }
+                    black_box(acc)
+                });
+            }
+        );
+        
+        group.bench_with_input(
+            BenchmarkId::new("WASM Pippenger MSM", n),
+            &n,
+            |b, _| {
+                b.iter(|| black_box(g1_msm(points.clone(), scalars.clone()).unwrap()));
+            }
+        );
+    }
+    
+    group.finish();
+}
+
+// Run with and without `--features parallel` to compare the two MSM paths
+fn benchmark_large_msm(c: &mut Criterion) {
+    let n = 4096;
+    let points: Vec<G1Point> = (0..n).map(|_| G1Point::random().unwrap()).collect();
+    let scalars: Vec<Scalar> = (0..n).map(|_| Scalar::random().unwrap()).collect();
+    
+    let mut group = c.benchmark_group("Large MSM");
+    group.sample_size(10);
+    group.bench_function(
+        BenchmarkId::new("WASM Pippenger MSM", n),
+        |b| b.iter(|| black_box(g1_msm(points.clone(), scalars.clone()).unwrap()))
+    );
+    
+    let g2_points: Vec<G2Point> = (0..n).map(|_| G2Point::random().unwrap()).collect();
+    group.bench_function(
+        BenchmarkId::new("WASM Pippenger G2 MSM", n),
+        |b| b.iter(|| black_box(g2_msm(g2_points.clone(), scalars.clone()).unwrap()))
+    );
+    group.finish();
+}
+
+fn benchmark_msm_window(c: &mut Criterion) {
+    let n = 1024;
+    let points: Vec<G1Point> = (0..n).map(|_| G1Point::random().unwrap()).collect();
+    let scalars: Vec<Scalar> = (0..n).map(|_| Scalar::random().unwrap()).collect();
+    
+    let mut group = c.benchmark_group("MSM Window Size");
+    group.sample_size(20);
+    
+    for window_bits in [4, 6, 7, 8, 9, 10, 12] {
+        group.bench_with_input(
+            BenchmarkId::new("g1_msm_windowed", window_bits),
+            &window_bits,
+            |b, &w| b.iter(|| black_box(g1_msm_windowed(points.clone(), scalars.clone(), w).unwrap()))
+        );
+    }
+    
+    group.finish();
+}
+
+fn benchmark_serialization(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Serialization");
+    group.sample_size(20);
+    
+    let n = 10_000;
+    let elements: Vec<FpElement> = (0..n).map(|_| FpElement::random().unwrap()).collect();
+    
+    group.bench_function(
+        BenchmarkId::new("FpElement to_hex", n),
+        |b| b.iter(|| {
+            for e in &elements {
+                black_box(e.to_hex());
+            }
+        })
+    );
+    
+    group.bench_function(
+        BenchmarkId::new("FpElement to_hex_into", n),
+        |b| b.iter(|| {
+            let mut buf = [0u8; 96];
+            for e in &elements {
+                e.to_hex_into(&mut buf).unwrap();
+                black_box(&buf);
+            }
+        })
+    );
+    
+    group.finish();
+}
+
+fn benchmark_batch_affine(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Batch Affine");
+    group.sample_size(20);
+    
+    let n = 1000;
+    let points: Vec<G1Point> = (0..n).map(|_| G1Point::random().unwrap()).collect();
+    
+    group.bench_function(
+        BenchmarkId::new("G1 coordinates loop", n),
+        |b| b.iter(|| {
+            for p in &points {
+                black_box(p.coordinates());
+            }
+        })
+    );
+    
+    group.bench_function(
+        BenchmarkId::new("G1 batch coordinates", n),
+        |b| b.iter(|| black_box(g1_batch_coordinates(&points)))
+    );
+    
+    group.finish();
+}
+
 fn benchmark_memory_usage(c: &mut Criterion) {
     c.bench_function("Memory Usage", |b| {
         b.iter(|| {
             // Test memory allocation patterns
             let elements: Vec<FpElement> = (0..1000)
-                .map(|_| FpElement::random())
+                .map(|_| FpElement::random().unwrap())
                 .collect();
             
             let points: Vec<G1Point> = (0..100)
-                .map(|_| G1Point::random())
+                .map(|_| G1Point::random().unwrap())
                 .collect();
             
             black_box((elements, points));
@@ -242,7 +481,16 @@ criterion_group!(
     benchmark_field_operations,
     benchmark_point_operations,
     benchmark_hash_functions,
+    benchmark_hash_algorithms,
     benchmark_pairing_operations,
+    benchmark_mul_add,
+    benchmark_fixed_base,
+    benchmark_generator_mul,
+    benchmark_msm,
+    benchmark_large_msm,
+    benchmark_msm_window,
+    benchmark_serialization,
+    benchmark_batch_affine,
     benchmark_memory_usage
 );
 criterion_main!(benches);
\ No newline at end of file
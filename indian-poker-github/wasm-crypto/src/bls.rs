@@ -0,0 +1,507 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use blst::{blst_p1, blst_p2, blst_scalar};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+use crate::{hash_to_g2, CryptoError, multi_pairing, pairing_check, G1Point, G2Point, GtElement, Scalar};
+
+/// Minimum input keying material accepted by `keygen`, per the BLS KeyGen spec
+const MIN_IKM_BYTES: usize = 32;
+
+/// Derive a secret key from input keying material
+///
+/// Runs the HKDF-SHA256 KeyGen procedure from the IETF BLS signature draft with
+/// an empty key_info, which is also the EIP-2333 master key derivation.
+#[wasm_bindgen(js_name = bls_keygen)]
+pub fn keygen(ikm: &[u8]) -> Result<SecretKey, CryptoError> {
+    SecretKey::from_ikm(ikm)
+}
+
+fn derive_key(ikm: &[u8]) -> Result<blst_scalar, CryptoError> {
+    if ikm.len() < MIN_IKM_BYTES {
+        return Err(CryptoError::InvalidParameter(format!(
+            "IKM must be at least {} bytes, got {}",
+            MIN_IKM_BYTES,
+            ikm.len()
+        )));
+    }
+    
+    let mut value = blst_scalar::default();
+    unsafe {
+        blst::blst_keygen(&mut value, ikm.as_ptr(), ikm.len(), std::ptr::null(), 0);
+    }
+    Ok(value)
+}
+
+/// BLS secret key, wiped from memory when dropped
+#[wasm_bindgen]
+pub struct SecretKey {
+    value: blst_scalar,
+}
+
+#[wasm_bindgen]
+impl SecretKey {
+    /// Derive a secret key from input keying material (see `keygen`)
+    pub fn from_ikm(ikm: &[u8]) -> Result<SecretKey, CryptoError> {
+        derive_key(ikm).map(|value| SecretKey { value })
+    }
+    
+    /// Take ownership of a scalar as a secret key, wiping the scalar's copy
+    pub fn from_scalar(scalar: Scalar) -> SecretKey {
+        let mut scalar = scalar;
+        let key = SecretKey { value: scalar.value.clone() };
+        scalar.value.b.zeroize();
+        key
+    }
+    
+    /// Public key `sk * g1`
+    pub fn public_key(&self) -> G1Point {
+        let mut point = blst_p1::default();
+        unsafe {
+            blst::blst_sk_to_pk_in_g1(&mut point, &self.value);
+        }
+        G1Point { point }
+    }
+    
+    /// Sign a message (see `sign`)
+    pub fn sign(&self, message: &[u8], dst: &[u8]) -> G2Point {
+        let hash = hash_to_g2(message, dst);
+        let mut point = blst_p2::default();
+        unsafe {
+            blst::blst_sign_pk_in_g1(&mut point, &hash.point, &self.value);
+        }
+        G2Point { point }
+    }
+}
+
+#[cfg(test)]
+impl SecretKey {
+    /// Big-endian key bytes, for comparing against published vectors
+    fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        unsafe {
+            blst::blst_bendian_from_scalar(bytes.as_mut_ptr(), &self.value);
+        }
+        bytes
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.value.b.zeroize();
+    }
+}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretKey(<redacted>)")
+    }
+}
+
+/// Sign a message (minimal-pubkey-size scheme: public keys in G1, signatures in G2)
+///
+/// The signature is `secret_key * H(message)` with `H` the RFC 9380 hash to G2 under `dst`.
+#[wasm_bindgen(js_name = bls_sign)]
+pub fn sign(secret_key: &Scalar, message: &[u8], dst: &[u8]) -> G2Point {
+    hash_to_g2(message, dst).scalar_mul_fr(secret_key)
+}
+
+/// Verify a signature produced by `sign`
+///
+/// Checks `e(g1, signature) == e(public_key, H(message))`. Public keys at infinity
+/// and points outside the prime-order subgroups are rejected.
+#[wasm_bindgen(js_name = bls_verify)]
+pub fn verify(public_key: &G1Point, message: &[u8], signature: &G2Point, dst: &[u8]) -> bool {
+    if public_key.is_infinity() || !public_key.is_in_subgroup() || !signature.is_in_subgroup() {
+        return false;
+    }
+    pairing_check(
+        vec![G1Point::generator().negate(), *public_key],
+        vec![*signature, hash_to_g2(message, dst)],
+    )
+}
+
+/// Aggregate signatures by summing them in G2
+#[wasm_bindgen(js_name = bls_aggregate_signatures)]
+pub fn aggregate_signatures(sigs: Vec<G2Point>) -> Result<G2Point, CryptoError> {
+    let (first, rest) = sigs
+        .split_first()
+        .ok_or(CryptoError::EmptyInput)?;
+    Ok(rest.iter().fold(*first, |acc, sig| acc.add(sig)))
+}
+
+/// Aggregate public keys by summing them in G1
+///
+/// An aggregate over the same message verifies against the aggregated key. The
+/// keys should come with proofs of possession to rule out rogue-key attacks.
+#[wasm_bindgen(js_name = bls_aggregate_public_keys)]
+pub fn aggregate_public_keys(pks: Vec<G1Point>) -> Result<G1Point, CryptoError> {
+    let (first, rest) = pks
+        .split_first()
+        .ok_or(CryptoError::EmptyInput)?;
+    Ok(rest.iter().fold(*first, |acc, pk| acc.add(pk)))
+}
+
+/// Aggregate public keys like `aggregate_public_keys`, rejecting any key that appears twice
+///
+/// Keys are compared by their compressed encoding, and the error names both indices.
+#[wasm_bindgen(js_name = bls_aggregate_public_keys_checked)]
+pub fn aggregate_public_keys_checked(pks: Vec<G1Point>) -> Result<G1Point, CryptoError> {
+    let mut seen = HashMap::with_capacity(pks.len());
+    for (second, pk) in pks.iter().enumerate() {
+        if let Some(first) = seen.insert(pk.compress(), second) {
+            return Err(CryptoError::DuplicateKey { first, second });
+        }
+    }
+    aggregate_public_keys(pks)
+}
+
+/// Verify an aggregate signature over distinct messages, one per signer
+///
+/// Checks `e(g1, agg_sig) == prod e(pks[i], H(msgs[i]))` with one multi-pairing.
+/// Mismatched lengths, empty input and duplicate messages are rejected; the last
+/// would otherwise let an aggregate be split across signers of the same message.
+#[wasm_bindgen(js_name = bls_aggregate_verify)]
+pub fn aggregate_verify(
+    pks: Vec<G1Point>,
+    msgs: Vec<js_sys::Uint8Array>,
+    agg_sig: &G2Point,
+    dst: &[u8],
+) -> bool {
+    let msgs: Vec<Vec<u8>> = msgs.iter().map(|msg| msg.to_vec()).collect();
+    aggregate_verify_bytes(&pks, &msgs, agg_sig, dst)
+}
+
+/// Rust-side entry point for `aggregate_verify`, taking the messages as byte slices
+pub fn aggregate_verify_bytes<M: AsRef<[u8]>>(
+    pks: &[G1Point],
+    msgs: &[M],
+    agg_sig: &G2Point,
+    dst: &[u8],
+) -> bool {
+    if pks.is_empty() || pks.len() != msgs.len() || !agg_sig.is_in_subgroup() {
+        return false;
+    }
+    if pks.iter().any(|pk| pk.is_infinity() || !pk.is_in_subgroup()) {
+        return false;
+    }
+    let mut seen = HashSet::with_capacity(msgs.len());
+    if !msgs.iter().all(|msg| seen.insert(msg.as_ref())) {
+        return false;
+    }
+    
+    let mut g1s = Vec::with_capacity(pks.len() + 1);
+    let mut g2s = Vec::with_capacity(pks.len() + 1);
+    g1s.push(G1Point::generator().negate());
+    g2s.push(*agg_sig);
+    for (pk, msg) in pks.iter().zip(msgs) {
+        g1s.push(*pk);
+        g2s.push(hash_to_g2(msg.as_ref(), dst));
+    }
+    
+    multi_pairing(g1s, g2s)
+        .map(|gt| gt.equals(&GtElement::one()))
+        .unwrap_or(false)
+}
+
+/// Domain separation tag for proofs of possession (minimal-pubkey-size, proof of possession)
+pub const POP_DST: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Prove possession of a secret key by signing the compressed public key under `POP_DST`
+#[wasm_bindgen(js_name = bls_pop_prove)]
+pub fn pop_prove(sk: &Scalar) -> G2Point {
+    let public_key = G1Point::generator().scalar_mul_fr(sk);
+    sign(sk, &public_key.compress(), POP_DST)
+}
+
+/// Verify a proof of possession produced by `pop_prove`
+#[wasm_bindgen(js_name = bls_pop_verify)]
+pub fn pop_verify(pk: &G1Point, pop: &G2Point) -> bool {
+    verify(pk, &pk.compress(), pop, POP_DST)
+}
+
+/// BLS public key, guaranteed to be a non-identity point of the G1 subgroup
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct PublicKey {
+    point: G1Point,
+}
+
+#[wasm_bindgen]
+impl PublicKey {
+    /// Decode a 48-byte compressed public key, rejecting infinity and non-subgroup points
+    pub fn from_bytes(bytes: &[u8]) -> Result<PublicKey, CryptoError> {
+        PublicKey::from_point(&G1Point::uncompress(bytes)?)
+    }
+    
+    /// Validate an existing point as a public key
+    pub fn from_point(point: &G1Point) -> Result<PublicKey, CryptoError> {
+        if point.is_infinity() {
+            return Err(CryptoError::PointAtInfinity);
+        }
+        if !point.is_in_subgroup() {
+            return Err(CryptoError::NotInSubgroup);
+        }
+        Ok(PublicKey { point: *point })
+    }
+    
+    /// 48-byte compressed encoding
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.point.compress()
+    }
+    
+    /// Underlying G1 point
+    pub fn point(&self) -> G1Point {
+        self.point
+    }
+}
+
+/// BLS signature, a G2 subgroup point
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct Signature {
+    point: G2Point,
+}
+
+#[wasm_bindgen]
+impl Signature {
+    /// Decode a 96-byte compressed signature, checking subgroup membership
+    pub fn from_bytes(bytes: &[u8]) -> Result<Signature, CryptoError> {
+        Signature::from_point(&G2Point::uncompress(bytes)?)
+    }
+    
+    /// Validate an existing point as a signature
+    pub fn from_point(point: &G2Point) -> Result<Signature, CryptoError> {
+        if !point.is_in_subgroup() {
+            return Err(CryptoError::NotInSubgroup);
+        }
+        Ok(Signature { point: *point })
+    }
+    
+    /// 96-byte compressed encoding
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.point.compress()
+    }
+    
+    /// Underlying G2 point
+    pub fn point(&self) -> G2Point {
+        self.point
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// EIP-2333 test vectors: seed and master secret key, the latter as big-endian hex
+    const EIP2333_MASTER_KEYS: [(&str, &str); 4] = [
+        (
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+            "0d7359d57963ab8fbbde1852dcf553fedbc31f464d80ee7d40ae683122b45070",
+        ),
+        (
+            "3141592653589793238462643383279502884197169399375105820974944592",
+            "41c9e07822b092a93fd6797396338c3ada4170cc81829fdfce6b5d34bd5e7ec7",
+        ),
+        (
+            "0099ff991111002299dd7744ee3355bbdd8844115566cc55663355668888cc00",
+            "3cfa341ab3910a7d00d933d8f7c4fe87c91798a0397421d6b19fd5b815132e80",
+        ),
+        (
+            "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3",
+            "2a0e28ffa5fbbe2f8e7aad4ed94f745d6bf755c51182e119bb1694fe61d3afca",
+        ),
+    ];
+    
+    #[test]
+    fn keygen_matches_eip2333() {
+        for (seed, master_sk) in EIP2333_MASTER_KEYS {
+            let sk = keygen(&hex::decode(seed).unwrap()).unwrap();
+            assert_eq!(hex::encode(sk.to_be_bytes()), master_sk);
+        }
+    }
+    
+    #[test]
+    fn keygen_rejects_short_ikm() {
+        assert!(matches!(keygen(&[0u8; 31]), Err(CryptoError::InvalidParameter(_))));
+    }
+    
+    #[test]
+    fn secret_key_debug_is_redacted() {
+        let sk = SecretKey::from_scalar(Scalar::from_u64(42));
+        assert_eq!(format!("{:?}", sk), "SecretKey(<redacted>)");
+    }
+    
+    #[test]
+    fn secret_key_signs_like_scalar() {
+        let scalar = Scalar::random_from_seed(b"bls secret key");
+        let sk = SecretKey::from_scalar(scalar.clone());
+        let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+        assert!(sk.public_key().equals(&G1Point::generator().scalar_mul_fr(&scalar)));
+        assert!(sk.sign(b"msg", dst).equals(&sign(&scalar, b"msg", dst)));
+    }
+    
+    #[test]
+    fn aggregate_public_keys_checked_rejects_duplicates() {
+        let keys: Vec<G1Point> = (0..4u8)
+            .map(|i| SecretKey::from_scalar(Scalar::random_from_seed(&[i])).public_key())
+            .collect();
+        let expected = keys.iter().fold(G1Point::identity(), |acc, pk| acc.add(pk));
+        assert!(aggregate_public_keys_checked(keys.clone()).unwrap().equals(&expected));
+        
+        let mut duplicated = keys.clone();
+        duplicated.push(keys[1]);
+        assert_eq!(
+            aggregate_public_keys_checked(duplicated).unwrap_err(),
+            CryptoError::DuplicateKey { first: 1, second: 4 }
+        );
+    }
+    
+    fn keypair(seed: &[u8]) -> (Scalar, G1Point) {
+        let sk = Scalar::random_from_seed(seed);
+        let pk = G1Point::generator().scalar_mul_fr(&sk);
+        (sk, pk)
+    }
+    
+    #[test]
+    fn signature_verifies_only_for_its_message() {
+        let (sk, pk) = keypair(b"signer");
+        let sig = sign(&sk, b"message", crate::ETH_BLS_DST);
+        assert!(verify(&pk, b"message", &sig, crate::ETH_BLS_DST));
+        assert!(!verify(&pk, b"other message", &sig, crate::ETH_BLS_DST));
+    }
+    
+    /// (4, y) is on E1 but outside the prime-order subgroup
+    fn off_subgroup_g1() -> G1Point {
+        G1Point::from_curve_coordinates(
+            "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004",
+            "0a989badd40d6212b33cffc3f3763e9bc760f988c9926b26da9dd85e928483446346b8ed00e1de5d5ea93e354abe706c",
+        )
+        .unwrap()
+    }
+    
+    /// (2, y) is on E2 but outside the prime-order subgroup
+    fn off_subgroup_g2() -> G2Point {
+        G2Point::from_curve_coordinates(
+            "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002",
+            "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "013a59858b6809fca4d9a3b6539246a70051a3c88899964a42bc9a69cf9acdd9dd387cfa9086b894185b9a46a402be73",
+            "02d27e0ec3356299a346a09ad7dc4ef68a483c3aed53f9139d2f929a3eecebf72082e5e58c6da24ee32e03040c406d4f",
+        )
+        .unwrap()
+    }
+    
+    #[test]
+    fn verify_rejects_tampered_signature() {
+        let (sk, pk) = keypair(b"signer");
+        let sig = sign(&sk, b"message", crate::ETH_BLS_DST);
+        assert!(verify(&pk, b"message", &sig, crate::ETH_BLS_DST));
+        
+        let tampered = sig.add(&G2Point::generator());
+        assert!(!verify(&pk, b"message", &tampered, crate::ETH_BLS_DST));
+        assert!(!off_subgroup_g2().is_in_subgroup());
+        assert!(!verify(&pk, b"message", &off_subgroup_g2(), crate::ETH_BLS_DST));
+    }
+    
+    #[test]
+    fn verify_rejects_rogue_keys() {
+        // The identity key would accept the identity signature for every message
+        let infinity = G1Point::identity();
+        assert!(!verify(&infinity, b"message", &G2Point::identity(), crate::ETH_BLS_DST));
+        assert_eq!(PublicKey::from_point(&infinity).unwrap_err(), CryptoError::PointAtInfinity);
+        
+        let rogue = off_subgroup_g1();
+        assert!(!rogue.is_in_subgroup());
+        let sig = hash_to_g2(b"message", crate::ETH_BLS_DST).scalar_mul_fr(&Scalar::from_u64(5));
+        assert!(!verify(&rogue, b"message", &sig, crate::ETH_BLS_DST));
+        assert_eq!(PublicKey::from_point(&rogue).unwrap_err(), CryptoError::NotInSubgroup);
+    }
+    
+    #[test]
+    fn aggregate_over_one_message_verifies_against_aggregate_key() {
+        let keys: Vec<(Scalar, G1Point)> = (0..3u8).map(|i| keypair(&[b'k', i])).collect();
+        let sigs = keys.iter().map(|(sk, _)| sign(sk, b"shared", crate::ETH_BLS_DST)).collect();
+        let agg_sig = aggregate_signatures(sigs).unwrap();
+        let agg_pk = aggregate_public_keys(keys.iter().map(|(_, pk)| *pk).collect()).unwrap();
+        
+        assert!(verify(&agg_pk, b"shared", &agg_sig, crate::ETH_BLS_DST));
+        assert!(!verify(&agg_pk, b"other", &agg_sig, crate::ETH_BLS_DST));
+    }
+    
+    #[test]
+    fn aggregate_of_nothing_is_an_error() {
+        assert_eq!(aggregate_signatures(vec![]).unwrap_err(), CryptoError::EmptyInput);
+        assert_eq!(aggregate_public_keys(vec![]).unwrap_err(), CryptoError::EmptyInput);
+    }
+    
+    #[test]
+    fn aggregate_verify_distinct_messages() {
+        let keys: Vec<(Scalar, G1Point)> = (0..3u8).map(|i| keypair(&[b'k', i])).collect();
+        let msgs: [&[u8]; 3] = [b"first", b"second", b"third"];
+        let sigs = keys.iter().zip(msgs).map(|((sk, _), msg)| sign(sk, msg, crate::ETH_BLS_DST)).collect();
+        let agg_sig = aggregate_signatures(sigs).unwrap();
+        let pks: Vec<G1Point> = keys.iter().map(|(_, pk)| *pk).collect();
+        
+        assert!(aggregate_verify_bytes(&pks, &msgs, &agg_sig, crate::ETH_BLS_DST));
+        
+        let swapped: [&[u8]; 3] = [b"second", b"first", b"third"];
+        assert!(!aggregate_verify_bytes(&pks, &swapped, &agg_sig, crate::ETH_BLS_DST));
+        assert!(!aggregate_verify_bytes(&pks[..2], &msgs[..2], &agg_sig, crate::ETH_BLS_DST));
+        assert!(!aggregate_verify_bytes(&pks, &msgs[..2], &agg_sig, crate::ETH_BLS_DST));
+        assert!(!aggregate_verify_bytes::<&[u8]>(&[], &[], &agg_sig, crate::ETH_BLS_DST));
+    }
+    
+    #[test]
+    fn aggregate_verify_rejects_duplicate_messages() {
+        let keys: Vec<(Scalar, G1Point)> = (0..2u8).map(|i| keypair(&[b'k', i])).collect();
+        let sigs = keys.iter().map(|(sk, _)| sign(sk, b"same", crate::ETH_BLS_DST)).collect();
+        let agg_sig = aggregate_signatures(sigs).unwrap();
+        let pks: Vec<G1Point> = keys.iter().map(|(_, pk)| *pk).collect();
+        
+        // The pairing equation holds, so only the duplicate check rejects this
+        assert!(!aggregate_verify_bytes(&pks, &[b"same", b"same"], &agg_sig, crate::ETH_BLS_DST));
+    }
+    
+    #[test]
+    fn proof_of_possession() {
+        let (sk, pk) = keypair(b"signer");
+        let (_, other_pk) = keypair(b"other signer");
+        let pop = pop_prove(&sk);
+        assert!(pop_verify(&pk, &pop));
+        assert!(!pop_verify(&other_pk, &pop));
+        
+        // The same message signed under the signing DST is not a proof of possession
+        let signed = sign(&sk, &pk.compress(), crate::ETH_BLS_DST);
+        assert!(!pop_verify(&pk, &signed));
+    }
+    
+    #[test]
+    fn public_key_from_bytes() {
+        let (_, pk) = keypair(b"signer");
+        let parsed = PublicKey::from_bytes(&pk.compress()).unwrap();
+        assert!(parsed.point().equals(&pk));
+        assert_eq!(parsed.to_bytes(), pk.compress());
+        
+        let mut infinity = [0u8; 48];
+        infinity[0] = 0xc0;
+        assert_eq!(PublicKey::from_bytes(&infinity).unwrap_err(), CryptoError::PointAtInfinity);
+        assert_eq!(PublicKey::from_bytes(&[0u8; 48]).unwrap_err(), CryptoError::InvalidEncoding);
+    }
+    
+    #[test]
+    fn signature_bytes_round_trip() {
+        let (sk, _) = keypair(b"signer");
+        let sig = Signature::from_point(&sign(&sk, b"message", crate::ETH_BLS_DST)).unwrap();
+        let bytes = sig.to_bytes();
+        assert_eq!(bytes.len(), 96);
+        assert!(Signature::from_bytes(&bytes).unwrap().point().equals(&sig.point()));
+        
+        assert_eq!(
+            Signature::from_bytes(&bytes[..95]).unwrap_err(),
+            CryptoError::WrongLength { expected: 96, got: 95 }
+        );
+        assert_eq!(Signature::from_point(&off_subgroup_g2()).unwrap_err(), CryptoError::NotInSubgroup);
+    }
+}
@@ -0,0 +1,196 @@
+use wasm_bindgen::prelude::*;
+
+use crate::transcript::Transcript;
+use crate::{CryptoError, G1Point, Scalar};
+
+/// ElGamal ciphertext `(r * G, message + r * pk)` over G1
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct ElGamalCiphertext {
+    c1: G1Point,
+    c2: G1Point,
+}
+
+#[wasm_bindgen]
+impl ElGamalCiphertext {
+    #[wasm_bindgen(constructor)]
+    pub fn new(c1: &G1Point, c2: &G1Point) -> ElGamalCiphertext {
+        ElGamalCiphertext { c1: *c1, c2: *c2 }
+    }
+    
+    /// Ephemeral component `r * G`
+    #[wasm_bindgen(getter)]
+    pub fn c1(&self) -> G1Point {
+        self.c1
+    }
+    
+    /// Masked message `message + r * pk`
+    #[wasm_bindgen(getter)]
+    pub fn c2(&self) -> G1Point {
+        self.c2
+    }
+}
+
+/// Encrypt a message point under `pk` with caller-supplied randomness
+///
+/// Cards are encoded as G1 points. `randomness` must be fresh and uniform for every
+/// encryption; reusing it leaks the difference of the two messages.
+#[wasm_bindgen(js_name = elgamal_encrypt)]
+pub fn encrypt(pk: &G1Point, message: &G1Point, randomness: &Scalar) -> ElGamalCiphertext {
+    ElGamalCiphertext {
+        c1: G1Point::generator().scalar_mul_fr(randomness),
+        c2: message.add(&pk.scalar_mul_fr(randomness)),
+    }
+}
+
+/// Decrypt a ciphertext: `c2 - sk * c1`
+#[wasm_bindgen(js_name = elgamal_decrypt)]
+pub fn decrypt(sk: &Scalar, ciphertext: &ElGamalCiphertext) -> G1Point {
+    ciphertext.c2.subtract(&ciphertext.c1.scalar_mul_fr(sk))
+}
+
+/// Re-randomize a ciphertext under `pk` without changing its plaintext
+///
+/// Adds an encryption of the identity, `(r' * G, r' * pk)`, so the result is
+/// unlinkable to the input. This is the building block of a verifiable shuffle.
+#[wasm_bindgen(js_name = elgamal_rerandomize)]
+pub fn rerandomize(pk: &G1Point, ciphertext: &ElGamalCiphertext, fresh_r: &Scalar) -> ElGamalCiphertext {
+    ElGamalCiphertext {
+        c1: ciphertext.c1.add(&G1Point::generator().scalar_mul_fr(fresh_r)),
+        c2: ciphertext.c2.add(&pk.scalar_mul_fr(fresh_r)),
+    }
+}
+
+/// Domain separation tag for the decryption proof challenge
+const DECRYPT_PROOF_DST: &[u8] = b"INDIAN-POKER-ELGAMAL-DECRYPT-PROOF-V1";
+
+/// Non-interactive Chaum–Pedersen proof that a ciphertext was decrypted with the key behind `pk`
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct DecryptionProof {
+    challenge: Scalar,
+    response: Scalar,
+}
+
+#[wasm_bindgen]
+impl DecryptionProof {
+    #[wasm_bindgen(constructor)]
+    pub fn new(challenge: &Scalar, response: &Scalar) -> DecryptionProof {
+        DecryptionProof {
+            challenge: challenge.clone(),
+            response: response.clone(),
+        }
+    }
+    
+    #[wasm_bindgen(getter)]
+    pub fn challenge(&self) -> Scalar {
+        self.challenge.clone()
+    }
+    
+    #[wasm_bindgen(getter)]
+    pub fn response(&self) -> Scalar {
+        self.response.clone()
+    }
+}
+
+/// Prove that `decrypt(sk, ciphertext)` is honest
+///
+/// Shows `log_G(pk) == log_c1(c2 - plaintext)` without revealing `sk`, with the
+/// challenge derived by Fiat–Shamir.
+#[wasm_bindgen]
+pub fn decrypt_proof(sk: &Scalar, ciphertext: &ElGamalCiphertext) -> Result<DecryptionProof, CryptoError> {
+    let pk = G1Point::generator().scalar_mul_fr(sk);
+    let plaintext = decrypt(sk, ciphertext);
+    
+    let nonce = Scalar::random()?;
+    let a = G1Point::generator().scalar_mul_fr(&nonce);
+    let b = ciphertext.c1.scalar_mul_fr(&nonce);
+    let challenge = decrypt_challenge(&pk, ciphertext, &plaintext, &a, &b);
+    let response = nonce.add(&challenge.mul(sk));
+    
+    Ok(DecryptionProof { challenge, response })
+}
+
+/// Verify that `plaintext` is the honest decryption of `ciphertext` under `pk`
+#[wasm_bindgen]
+pub fn verify_decrypt_proof(
+    pk: &G1Point,
+    ciphertext: &ElGamalCiphertext,
+    plaintext: &G1Point,
+    proof: &DecryptionProof,
+) -> bool {
+    let shared = ciphertext.c2.subtract(plaintext);
+    let a = G1Point::generator()
+        .scalar_mul_fr(&proof.response)
+        .subtract(&pk.scalar_mul_fr(&proof.challenge));
+    let b = ciphertext
+        .c1
+        .scalar_mul_fr(&proof.response)
+        .subtract(&shared.scalar_mul_fr(&proof.challenge));
+    
+    let expected = decrypt_challenge(pk, ciphertext, plaintext, &a, &b);
+    expected.equals(&proof.challenge)
+}
+
+fn decrypt_challenge(
+    pk: &G1Point,
+    ciphertext: &ElGamalCiphertext,
+    plaintext: &G1Point,
+    a: &G1Point,
+    b: &G1Point,
+) -> Scalar {
+    let mut transcript = Transcript::new(DECRYPT_PROOF_DST);
+    transcript.append_point(b"pk", pk);
+    transcript.append_point(b"c1", &ciphertext.c1);
+    transcript.append_point(b"c2", &ciphertext.c2);
+    transcript.append_point(b"plaintext", plaintext);
+    transcript.append_point(b"a", a);
+    transcript.append_point(b"b", b);
+    transcript.challenge_scalar(b"challenge")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn keypair(seed: &[u8]) -> (Scalar, G1Point) {
+        let sk = Scalar::random_from_seed(seed);
+        let pk = G1Point::generator().scalar_mul_fr(&sk);
+        (sk, pk)
+    }
+    
+    #[test]
+    fn decrypt_inverts_encrypt() {
+        let (sk, pk) = keypair(b"elgamal key");
+        let message = G1Point::random_from_seed(b"card");
+        let ciphertext = encrypt(&pk, &message, &Scalar::random_from_seed(b"randomness"));
+        assert!(decrypt(&sk, &ciphertext).equals(&message));
+    }
+    
+    #[test]
+    fn rerandomize_keeps_the_plaintext() {
+        let (sk, pk) = keypair(b"elgamal key");
+        let message = G1Point::random_from_seed(b"card");
+        let ciphertext = encrypt(&pk, &message, &Scalar::random_from_seed(b"randomness"));
+        
+        let fresh = rerandomize(&pk, &ciphertext, &Scalar::random_from_seed(b"fresh randomness"));
+        assert!(!fresh.c1().equals(&ciphertext.c1()));
+        assert!(!fresh.c2().equals(&ciphertext.c2()));
+        assert!(decrypt(&sk, &fresh).equals(&message));
+    }
+    
+    #[cfg(not(feature = "failing-rng"))]
+    #[test]
+    fn decryption_proof_binds_plaintext_and_key() {
+        let (sk, pk) = keypair(b"elgamal key");
+        let (_, other_pk) = keypair(b"other key");
+        let message = G1Point::random_from_seed(b"card");
+        let ciphertext = encrypt(&pk, &message, &Scalar::random_from_seed(b"randomness"));
+        let proof = decrypt_proof(&sk, &ciphertext).unwrap();
+        
+        assert!(verify_decrypt_proof(&pk, &ciphertext, &message, &proof));
+        let wrong_plaintext = G1Point::random_from_seed(b"other card");
+        assert!(!verify_decrypt_proof(&pk, &ciphertext, &wrong_plaintext, &proof));
+        assert!(!verify_decrypt_proof(&other_pk, &ciphertext, &message, &proof));
+    }
+}
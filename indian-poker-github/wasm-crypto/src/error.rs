@@ -0,0 +1,99 @@
+use std::fmt;
+
+use wasm_bindgen::prelude::*;
+
+/// Error returned by every fallible operation in this crate
+///
+/// Crosses into JS as an `Error` named "CryptoError" with a stable `code`
+/// property (e.g. "WRONG_LENGTH") that callers can match on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CryptoError {
+    /// Input is not valid hexadecimal
+    InvalidHex(String),
+    /// Input has the wrong number of bytes
+    WrongLength { expected: usize, got: usize },
+    /// Encoding is valid but not in canonical form (unreduced or nonzero padding)
+    NonCanonical(&'static str),
+    /// Point encoding has malformed flag bits
+    InvalidEncoding,
+    /// Point does not satisfy the curve equation
+    NotOnCurve,
+    /// Point is on the curve but outside the prime-order subgroup
+    NotInSubgroup,
+    /// Point at infinity where a non-identity point is required
+    PointAtInfinity,
+    /// Attempted to invert zero
+    ZeroInverse,
+    /// Paired inputs have different lengths
+    LengthMismatch { left: usize, right: usize },
+    /// Input collection is empty
+    EmptyInput,
+    /// The same public key appears twice, at these two indices
+    DuplicateKey { first: usize, second: usize },
+    /// Argument outside the supported range or set of values
+    InvalidParameter(String),
+    /// Failed to convert to or from a JS value
+    Serialization(String),
+    /// The platform random number generator is unavailable
+    RngUnavailable(String),
+}
+
+impl CryptoError {
+    /// Stable machine-readable error code
+    pub fn code(&self) -> &'static str {
+        match self {
+            CryptoError::InvalidHex(_) => "INVALID_HEX",
+            CryptoError::WrongLength { .. } => "WRONG_LENGTH",
+            CryptoError::NonCanonical(_) => "NON_CANONICAL",
+            CryptoError::InvalidEncoding => "INVALID_ENCODING",
+            CryptoError::NotOnCurve => "NOT_ON_CURVE",
+            CryptoError::NotInSubgroup => "NOT_IN_SUBGROUP",
+            CryptoError::PointAtInfinity => "POINT_AT_INFINITY",
+            CryptoError::ZeroInverse => "ZERO_INVERSE",
+            CryptoError::LengthMismatch { .. } => "LENGTH_MISMATCH",
+            CryptoError::EmptyInput => "EMPTY_INPUT",
+            CryptoError::DuplicateKey { .. } => "DUPLICATE_KEY",
+            CryptoError::InvalidParameter(_) => "INVALID_PARAMETER",
+            CryptoError::Serialization(_) => "SERIALIZATION",
+            CryptoError::RngUnavailable(_) => "RNG_UNAVAILABLE",
+        }
+    }
+}
+
+impl fmt::Display for CryptoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CryptoError::InvalidHex(e) => write!(f, "Invalid hex string: {}", e),
+            CryptoError::WrongLength { expected, got } => {
+                write!(f, "Wrong length: expected {} bytes, got {}", expected, got)
+            }
+            CryptoError::NonCanonical(what) => write!(f, "Non-canonical encoding: {}", what),
+            CryptoError::InvalidEncoding => write!(f, "Invalid point encoding"),
+            CryptoError::NotOnCurve => write!(f, "Point is not on the curve"),
+            CryptoError::NotInSubgroup => write!(f, "Point is not in the subgroup"),
+            CryptoError::PointAtInfinity => write!(f, "Point must not be the point at infinity"),
+            CryptoError::ZeroInverse => write!(f, "Cannot invert zero"),
+            CryptoError::LengthMismatch { left, right } => {
+                write!(f, "Mismatched input lengths: {} and {}", left, right)
+            }
+            CryptoError::EmptyInput => write!(f, "Input must not be empty"),
+            CryptoError::DuplicateKey { first, second } => {
+                write!(f, "Duplicate public key at indices {} and {}", first, second)
+            }
+            CryptoError::InvalidParameter(e) => write!(f, "Invalid parameter: {}", e),
+            CryptoError::Serialization(e) => write!(f, "Serialization failed: {}", e),
+            CryptoError::RngUnavailable(e) => write!(f, "Random number generator unavailable: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CryptoError {}
+
+impl From<CryptoError> for JsValue {
+    fn from(err: CryptoError) -> JsValue {
+        let js_err = js_sys::Error::new(&err.to_string());
+        js_err.set_name("CryptoError");
+        let _ = js_sys::Reflect::set(&js_err, &JsValue::from_str("code"), &JsValue::from_str(err.code()));
+        js_err.into()
+    }
+}
@@ -0,0 +1,93 @@
+use ::hkdf::Hkdf;
+use sha2::Sha256;
+use wasm_bindgen::prelude::*;
+
+use crate::CryptoError;
+
+/// HKDF-Extract over SHA-256 (RFC 5869), returning the 32-byte pseudorandom key
+#[wasm_bindgen]
+pub fn hkdf_extract(salt: &[u8], ikm: &[u8]) -> Vec<u8> {
+    let (prk, _) = Hkdf::<Sha256>::extract(Some(salt), ikm);
+    prk.to_vec()
+}
+
+/// HKDF-Expand over SHA-256 (RFC 5869)
+///
+/// Fails when `prk` is shorter than 32 bytes or `length` exceeds 255 * 32 bytes.
+#[wasm_bindgen]
+pub fn hkdf_expand(prk: &[u8], info: &[u8], length: usize) -> Result<Vec<u8>, CryptoError> {
+    let hk = Hkdf::<Sha256>::from_prk(prk)
+        .map_err(|_| CryptoError::InvalidParameter("HKDF pseudorandom key must be at least 32 bytes".to_string()))?;
+    let mut okm = vec![0u8; length];
+    hk.expand(info, &mut okm).map_err(|_| {
+        CryptoError::InvalidParameter(format!("HKDF output length {} exceeds {} bytes", length, 255 * 32))
+    })?;
+    Ok(okm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// RFC 5869 appendix A.1-A.3 as (ikm, salt, info, prk, okm)
+    const RFC5869_VECTORS: [(&str, &str, &str, &str, &str); 3] = [
+        (
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "000102030405060708090a0b0c",
+            "f0f1f2f3f4f5f6f7f8f9",
+            "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5",
+            "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865",
+        ),
+        (
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f\
+             202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f\
+             404142434445464748494a4b4c4d4e4f",
+            "606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f\
+             808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f\
+             a0a1a2a3a4a5a6a7a8a9aaabacadaeaf",
+            "b0b1b2b3b4b5b6b7b8b9babbbcbdbebfc0c1c2c3c4c5c6c7c8c9cacbcccdcecf\
+             d0d1d2d3d4d5d6d7d8d9dadbdcdddedfe0e1e2e3e4e5e6e7e8e9eaebecedeeef\
+             f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+            "06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244",
+            "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c\
+             59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71\
+             cc30c58179ec3e87c14c01d5c1f3434f1d87",
+        ),
+        (
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "",
+            "",
+            "19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04",
+            "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8",
+        ),
+    ];
+    
+    #[test]
+    fn rfc5869_vectors() {
+        for (ikm, salt, info, prk, okm) in RFC5869_VECTORS {
+            let ikm = hex::decode(ikm).unwrap();
+            let salt = hex::decode(salt).unwrap();
+            let info = hex::decode(info).unwrap();
+            let okm = hex::decode(okm).unwrap();
+            
+            let extracted = hkdf_extract(&salt, &ikm);
+            assert_eq!(hex::encode(&extracted), prk);
+            assert_eq!(hkdf_expand(&extracted, &info, okm.len()).unwrap(), okm);
+        }
+    }
+    
+    #[test]
+    fn expand_length_limit() {
+        let prk = [0x42; 32];
+        assert_eq!(hkdf_expand(&prk, b"", 255 * 32).unwrap().len(), 255 * 32);
+        assert!(matches!(
+            hkdf_expand(&prk, b"", 255 * 32 + 1),
+            Err(CryptoError::InvalidParameter(_))
+        ));
+    }
+    
+    #[test]
+    fn expand_rejects_short_prk() {
+        assert!(matches!(hkdf_expand(&[0x42; 31], b"", 32), Err(CryptoError::InvalidParameter(_))));
+    }
+}
@@ -0,0 +1,281 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{g1_msm, g1_mul_generator, g2_mul_generator, pairing_equal, CryptoError, G1Point, G2Point, Scalar};
+
+/// Structured reference string for KZG commitments: [τ^i]_1 for i below `max_coefficients`, and [1]_2, [τ]_2
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct KzgSetup {
+    g1_powers: Vec<G1Point>,
+    g2_powers: Vec<G2Point>,
+}
+
+#[wasm_bindgen]
+impl KzgSetup {
+    /// Build a setup from a known τ, supporting polynomials of up to `max_coefficients` coefficients
+    ///
+    /// Anyone who knows τ can open a commitment to any value, so this is only for tests
+    /// and demos. Real deployments load the output of a trusted-setup ceremony.
+    #[wasm_bindgen]
+    pub fn insecure_from_secret(tau: &Scalar, max_coefficients: usize) -> Result<KzgSetup, CryptoError> {
+        if max_coefficients == 0 {
+            return Err(CryptoError::InvalidParameter("a KZG setup needs at least one power".to_string()));
+        }
+        
+        let mut power = Scalar::from_u64(1);
+        let mut g1_powers = Vec::with_capacity(max_coefficients);
+        for _ in 0..max_coefficients {
+            g1_powers.push(g1_mul_generator(&power));
+            power = power.mul(tau);
+        }
+        
+        let g2_powers = vec![G2Point::generator(), g2_mul_generator(tau)];
+        
+        Ok(KzgSetup { g1_powers, g2_powers })
+    }
+    
+    /// Load a setup from concatenated compressed points: [τ^i]_1 in 48-byte chunks, then [τ^i]_2 in 96-byte chunks
+    ///
+    /// Every point is checked for curve and subgroup membership. At least one G1 power
+    /// and two G2 powers ([1]_2 and [τ]_2) are required; further G2 powers are kept but unused.
+    #[wasm_bindgen]
+    pub fn from_bytes(g1_powers: &[u8], g2_powers: &[u8]) -> Result<KzgSetup, CryptoError> {
+        let g1_powers = decode_points(g1_powers, 48, G1Point::uncompress)?;
+        let g2_powers = decode_points(g2_powers, 96, G2Point::uncompress)?;
+        if g1_powers.is_empty() {
+            return Err(CryptoError::EmptyInput);
+        }
+        if g2_powers.len() < 2 {
+            return Err(CryptoError::InvalidParameter(format!(
+                "a KZG setup needs [1]_2 and [τ]_2, got {} G2 powers",
+                g2_powers.len()
+            )));
+        }
+        
+        Ok(KzgSetup { g1_powers, g2_powers })
+    }
+    
+    /// Number of G1 powers, one more than the largest supported degree
+    #[wasm_bindgen(getter)]
+    pub fn max_coefficients(&self) -> usize {
+        self.g1_powers.len()
+    }
+    
+    /// [τ^i]_1
+    #[wasm_bindgen]
+    pub fn g1_power(&self, i: usize) -> Option<G1Point> {
+        self.g1_powers.get(i).copied()
+    }
+    
+    /// [τ^i]_2
+    #[wasm_bindgen]
+    pub fn g2_power(&self, i: usize) -> Option<G2Point> {
+        self.g2_powers.get(i).copied()
+    }
+}
+
+/// Split `bytes` into `size`-byte encodings and decode each, rejecting a truncated tail
+fn decode_points<T>(
+    bytes: &[u8],
+    size: usize,
+    decode: fn(&[u8]) -> Result<T, CryptoError>,
+) -> Result<Vec<T>, CryptoError> {
+    if !bytes.len().is_multiple_of(size) {
+        return Err(CryptoError::WrongLength {
+            expected: bytes.len().next_multiple_of(size),
+            got: bytes.len(),
+        });
+    }
+    bytes.chunks_exact(size).map(decode).collect()
+}
+
+/// Evaluation of a committed polynomial at a point, with the proof that it is correct
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct KzgOpening {
+    value: Scalar,
+    proof: G1Point,
+}
+
+#[wasm_bindgen]
+impl KzgOpening {
+    #[wasm_bindgen(constructor)]
+    pub fn new(value: &Scalar, proof: &G1Point) -> KzgOpening {
+        KzgOpening {
+            value: value.clone(),
+            proof: *proof,
+        }
+    }
+    
+    /// The claimed evaluation p(z)
+    #[wasm_bindgen(getter)]
+    pub fn value(&self) -> Scalar {
+        self.value.clone()
+    }
+    
+    /// Commitment to the quotient (p(x) - p(z)) / (x - z)
+    #[wasm_bindgen(getter)]
+    pub fn proof(&self) -> G1Point {
+        self.proof
+    }
+}
+
+/// Commit to the polynomial with coefficients `coeffs` (constant term first) as sum(c_i·[τ^i]_1)
+///
+/// The zero polynomial, including an empty `coeffs`, commits to the point at infinity.
+#[wasm_bindgen(js_name = kzg_commit)]
+pub fn commit(coeffs: Vec<Scalar>, setup: &KzgSetup) -> Result<G1Point, CryptoError> {
+    if coeffs.len() > setup.g1_powers.len() {
+        return Err(CryptoError::InvalidParameter(format!(
+            "polynomial has {} coefficients but the setup supports at most {}",
+            coeffs.len(),
+            setup.g1_powers.len()
+        )));
+    }
+    
+    g1_msm(setup.g1_powers[..coeffs.len()].to_vec(), coeffs)
+}
+
+/// Evaluate the polynomial at `point` and prove the evaluation against its commitment
+#[wasm_bindgen(js_name = kzg_open)]
+pub fn open(coeffs: Vec<Scalar>, point: &Scalar, setup: &KzgSetup) -> Result<KzgOpening, CryptoError> {
+    // Synthetic division by (x - point), from the leading coefficient down; the
+    // remainder is the evaluation
+    let mut value = Scalar::from_u64(0);
+    let mut quotient = vec![Scalar::from_u64(0); coeffs.len().saturating_sub(1)];
+    for (i, c) in coeffs.iter().enumerate().rev() {
+        value = value.mul(point).add(c);
+        if i > 0 {
+            quotient[i - 1] = value.clone();
+        }
+    }
+    
+    let proof = commit(quotient, setup)?;
+    Ok(KzgOpening { value, proof })
+}
+
+/// Check that `commitment` opens to `value` at `point`: e(C - [value]_1, [1]_2) == e(proof, [τ]_2 - [point]_2)
+#[wasm_bindgen(js_name = kzg_verify)]
+pub fn verify(commitment: &G1Point, point: &Scalar, value: &Scalar, proof: &G1Point, setup: &KzgSetup) -> bool {
+    if !commitment.is_in_subgroup() || !proof.is_in_subgroup() {
+        return false;
+    }
+    
+    let g2 = setup.g2_powers[0];
+    let shifted_commitment = commitment.subtract(&setup.g1_powers[0].scalar_mul_fr(value));
+    let shifted_tau = setup.g2_powers[1].subtract(&g2.scalar_mul_fr(point));
+    pairing_equal(&shifted_commitment, &g2, proof, &shifted_tau)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn test_setup(max_coefficients: usize) -> (Scalar, KzgSetup) {
+        let tau = Scalar::random_from_seed(b"kzg tau");
+        let setup = KzgSetup::insecure_from_secret(&tau, max_coefficients).unwrap();
+        (tau, setup)
+    }
+    
+    fn test_polynomial(len: usize) -> Vec<Scalar> {
+        (0..len).map(|i| Scalar::random_from_seed(&[b'c', i as u8])).collect()
+    }
+    
+    /// Horner evaluation of `coeffs` at `x`
+    fn evaluate(coeffs: &[Scalar], x: &Scalar) -> Scalar {
+        coeffs.iter().rev().fold(Scalar::from_u64(0), |acc, c| acc.mul(x).add(c))
+    }
+    
+    #[test]
+    fn commit_is_evaluation_at_tau() {
+        let (tau, setup) = test_setup(8);
+        
+        let constant = vec![Scalar::from_u64(7)];
+        assert!(commit(constant, &setup).unwrap().equals(&g1_mul_generator(&Scalar::from_u64(7))));
+        
+        let coeffs = test_polynomial(8);
+        let expected = g1_mul_generator(&evaluate(&coeffs, &tau));
+        assert!(commit(coeffs, &setup).unwrap().equals(&expected));
+        
+        assert!(commit(vec![], &setup).unwrap().is_infinity());
+        assert!(matches!(commit(test_polynomial(9), &setup), Err(CryptoError::InvalidParameter(_))));
+    }
+    
+    #[test]
+    fn honest_opening_verifies() {
+        let (_, setup) = test_setup(8);
+        let coeffs = test_polynomial(8);
+        let point = Scalar::random_from_seed(b"kzg point");
+        let commitment = commit(coeffs.clone(), &setup).unwrap();
+        
+        let opening = open(coeffs.clone(), &point, &setup).unwrap();
+        assert!(opening.value().equals(&evaluate(&coeffs, &point)));
+        assert!(verify(&commitment, &point, &opening.value(), &opening.proof(), &setup));
+    }
+    
+    #[test]
+    fn tampered_opening_fails() {
+        let (_, setup) = test_setup(8);
+        let coeffs = test_polynomial(8);
+        let point = Scalar::random_from_seed(b"kzg point");
+        let commitment = commit(coeffs.clone(), &setup).unwrap();
+        let opening = open(coeffs, &point, &setup).unwrap();
+        
+        let wrong_value = opening.value().add(&Scalar::from_u64(1));
+        assert!(!verify(&commitment, &point, &wrong_value, &opening.proof(), &setup));
+        
+        let wrong_point = point.add(&Scalar::from_u64(1));
+        assert!(!verify(&commitment, &wrong_point, &opening.value(), &opening.proof(), &setup));
+        
+        let wrong_proof = opening.proof().add(&G1Point::generator());
+        assert!(!verify(&commitment, &point, &opening.value(), &wrong_proof, &setup));
+    }
+    
+    fn setup_bytes(setup: &KzgSetup) -> (Vec<u8>, Vec<u8>) {
+        let g1 = setup.g1_powers.iter().flat_map(|p| p.compress()).collect();
+        let g2 = setup.g2_powers.iter().flat_map(|p| p.compress()).collect();
+        (g1, g2)
+    }
+    
+    #[test]
+    fn from_bytes_round_trip() {
+        let (_, setup) = test_setup(4);
+        let (g1, g2) = setup_bytes(&setup);
+        let loaded = KzgSetup::from_bytes(&g1, &g2).unwrap();
+        
+        assert_eq!(loaded.max_coefficients(), 4);
+        let coeffs = test_polynomial(4);
+        let point = Scalar::random_from_seed(b"kzg point");
+        let commitment = commit(coeffs.clone(), &setup).unwrap();
+        let opening = open(coeffs, &point, &loaded).unwrap();
+        assert!(verify(&commitment, &point, &opening.value(), &opening.proof(), &loaded));
+    }
+    
+    #[test]
+    fn from_bytes_rejects_bad_input() {
+        let (_, setup) = test_setup(4);
+        let (g1, g2) = setup_bytes(&setup);
+        
+        assert_eq!(
+            KzgSetup::from_bytes(&g1[..g1.len() - 1], &g2).unwrap_err(),
+            CryptoError::WrongLength { expected: 192, got: 191 }
+        );
+        assert_eq!(
+            KzgSetup::from_bytes(&g1, &g2[..100]).unwrap_err(),
+            CryptoError::WrongLength { expected: 192, got: 100 }
+        );
+        
+        // x = 4 is on the curve but outside the prime-order subgroup
+        let mut off_subgroup = g1.clone();
+        off_subgroup[48..96].fill(0);
+        off_subgroup[48] = 0x80;
+        off_subgroup[95] = 4;
+        assert_eq!(KzgSetup::from_bytes(&off_subgroup, &g2).unwrap_err(), CryptoError::NotInSubgroup);
+        
+        assert_eq!(KzgSetup::from_bytes(&[], &g2).unwrap_err(), CryptoError::EmptyInput);
+        assert!(matches!(
+            KzgSetup::from_bytes(&g1, &g2[..96]),
+            Err(CryptoError::InvalidParameter(_))
+        ));
+    }
+}
@@ -1,6 +1,22 @@
 use wasm_bindgen::prelude::*;
-use blst::{blst_p1, blst_p1_affine, blst_p2, blst_p2_affine, blst_fp, blst_fp2};
+use blst::{blst_p1, blst_p1_affine, blst_p2, blst_p2_affine, blst_fp, blst_fp2, blst_fp12, blst_fr, blst_scalar};
 use serde::{Deserialize, Serialize};
+use std::hint::black_box;
+use subtle::ConstantTimeEq;
+
+pub mod bls;
+pub mod elgamal;
+mod error;
+pub mod hkdf;
+pub mod kzg;
+pub mod merkle;
+pub mod mimc;
+pub mod pedersen;
+pub mod poseidon;
+pub mod schnorr;
+pub mod transcript;
+
+pub use error::CryptoError;
 
 // Import memory for WASM
 #[wasm_bindgen]
@@ -45,6 +61,20 @@ pub struct G2Point {
     pub(crate) point: blst_p2,
 }
 
+/// BLS12-381 scalar field element (F_r), always reduced modulo the group order r
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Scalar {
+    pub(crate) value: blst_scalar,
+}
+
+/// BLS12-381 target group element (GT ⊂ F_p¹²), the output of a pairing
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct GtElement {
+    pub(crate) value: blst_fp12,
+}
+
 /// Hash result for cryptographic hash functions
 #[wasm_bindgen]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -71,9 +101,8 @@ impl HashResult {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PerformanceMetrics {
     operation: String,
+    iterations: u32,
     wasm_time_us: u64,
-    js_time_us: u64,
-    speedup: f64,
     memory_used_kb: u64,
 }
 
@@ -85,18 +114,13 @@ impl PerformanceMetrics {
     }
 
     #[wasm_bindgen(getter)]
-    pub fn wasm_time_us(&self) -> u64 {
-        self.wasm_time_us
-    }
-
-    #[wasm_bindgen(getter)]
-    pub fn js_time_us(&self) -> u64 {
-        self.js_time_us
+    pub fn iterations(&self) -> u32 {
+        self.iterations
     }
 
     #[wasm_bindgen(getter)]
-    pub fn speedup(&self) -> f64 {
-        self.speedup
+    pub fn wasm_time_us(&self) -> u64 {
+        self.wasm_time_us
     }
 
     #[wasm_bindgen(getter)]
@@ -105,16 +129,43 @@ impl PerformanceMetrics {
     }
 }
 
+impl PerformanceMetrics {
+    /// Shape the metric like cargo-criterion's `benchmark-complete` message, in nanoseconds
+    fn to_criterion_record(&self) -> serde_json::Value {
+        let time_ns = (self.wasm_time_us * 1000) as f64;
+        let estimate = serde_json::json!({
+            "estimate": time_ns,
+            "lower_bound": time_ns,
+            "upper_bound": time_ns,
+            "unit": "ns"
+        });
+        
+        serde_json::json!({
+            "reason": "benchmark-complete",
+            "id": format!("wasm/{}", self.operation),
+            "unit": "ns",
+            "typical": estimate,
+            "mean": estimate,
+            "median": estimate
+        })
+    }
+}
+
 #[wasm_bindgen]
 impl FpElement {
     /// Create a new field element from a hex string
     #[wasm_bindgen(constructor)]
-    pub fn new(hex_string: &str) -> Result<FpElement, JsValue> {
+    pub fn new(hex_string: &str) -> Result<FpElement, CryptoError> {
         let bytes = hex::decode(hex_string)
-            .map_err(|e| JsValue::from_str(&format!("Invalid hex string: {}", e)))?;
-        
+            .map_err(|e| CryptoError::InvalidHex(e.to_string()))?;
+        FpElement::from_bytes(&bytes)
+    }
+    
+    /// Create a field element from 48 big-endian bytes, the binary form of `new`
+    #[wasm_bindgen]
+    pub fn from_bytes(bytes: &[u8]) -> Result<FpElement, CryptoError> {
         if bytes.len() != 48 {
-            return Err(JsValue::from_str("Field element must be 48 bytes"));
+            return Err(CryptoError::WrongLength { expected: 48, got: bytes.len() });
         }
         
         let mut value = blst_fp::default();
@@ -127,16 +178,14 @@ impl FpElement {
     
     /// Create a random field element
     #[wasm_bindgen]
-    pub fn random() -> FpElement {
-        let mut bytes = [0u8; 48];
-        getrandom::getrandom(&mut bytes).unwrap();
-        
-        let mut value = blst_fp::default();
-        unsafe {
-            blst::blst_fp_from_bendian(&mut value, bytes.as_ptr());
-        }
-        
-        FpElement { value }
+    pub fn random() -> Result<FpElement, CryptoError> {
+        FpElement::random_with(fill_random)
+    }
+    
+    /// Deterministic "random" field element: the same seed always yields the same element
+    #[wasm_bindgen]
+    pub fn random_from_seed(seed: &[u8]) -> FpElement {
+        FpElement::random_with(seeded_fill(seed)).expect("seeded RNG cannot fail")
     }
     
     /// Add two field elements
@@ -161,7 +210,7 @@ impl FpElement {
     
     /// Compute the multiplicative inverse
     #[wasm_bindgen]
-    pub fn inverse(&self) -> Result<FpElement, JsValue> {
+    pub fn inverse(&self) -> Result<FpElement, CryptoError> {
         let mut result = blst_fp::default();
         unsafe {
             blst::blst_fp_inverse(&mut result, &self.value);
@@ -169,7 +218,7 @@ impl FpElement {
         
         // Check if result is valid
         if self.is_zero() {
-            return Err(JsValue::from_str("Cannot invert zero element"));
+            return Err(CryptoError::ZeroInverse);
         }
         
         Ok(FpElement { value: result })
@@ -178,35 +227,159 @@ impl FpElement {
     /// Check if field element is zero
     #[wasm_bindgen]
     pub fn is_zero(&self) -> bool {
-        let mut bytes = [0u8; 48];
-        unsafe {
-            blst::blst_bendian_from_fp(bytes.as_mut_ptr(), &self.value);
-        }
-        bytes.iter().all(|&b| b == 0)
+        fp_to_bytes(&self.value).ct_eq(&[0u8; 48]).into()
+    }
+    
+    /// Constant-time equality
+    #[wasm_bindgen]
+    pub fn equals(&self, other: &FpElement) -> bool {
+        fp_to_bytes(&self.value).ct_eq(&fp_to_bytes(&other.value)).into()
     }
     
     /// Convert to hex string
     #[wasm_bindgen]
     pub fn to_hex(&self) -> String {
+        fp_to_hex(&self.value)
+    }
+    
+    /// Write the 96-character hex encoding into `buf` without allocating
+    #[wasm_bindgen]
+    pub fn to_hex_into(&self, buf: &mut [u8]) -> Result<(), CryptoError> {
+        if buf.len() != 96 {
+            return Err(CryptoError::WrongLength { expected: 96, got: buf.len() });
+        }
+        hex::encode_to_slice(fp_to_bytes(&self.value), buf)
+            .expect("buffer length checked above");
+        Ok(())
+    }
+    
+    /// Raw internal limbs: the Montgomery residue `a·R mod p` (R = 2^384) as 48 little-endian bytes
+    ///
+    /// NOT the canonical big-endian encoding of the element; only useful for exchanging values
+    /// with other blst-based code that works on `blst_fp` directly. Use `to_hex` otherwise.
+    #[wasm_bindgen]
+    pub fn to_montgomery_bytes(&self) -> Vec<u8> {
+        self.value.l.iter().flat_map(|limb| limb.to_le_bytes()).collect()
+    }
+    
+    /// Inverse of `to_montgomery_bytes`; the residue must be below p
+    #[wasm_bindgen]
+    pub fn from_montgomery_bytes(bytes: &[u8]) -> Result<FpElement, CryptoError> {
+        if bytes.len() != 48 {
+            return Err(CryptoError::WrongLength { expected: 48, got: bytes.len() });
+        }
+        
+        // A big-endian round-trip only preserves values that are already reduced
+        let mut be = [0u8; 48];
+        be.copy_from_slice(bytes);
+        be.reverse();
+        let mut reduced = blst_fp::default();
+        unsafe {
+            blst::blst_fp_from_bendian(&mut reduced, be.as_ptr());
+        }
+        if fp_to_bytes(&reduced) != be {
+            return Err(CryptoError::NonCanonical("Montgomery residue is not reduced modulo p"));
+        }
+        
+        let mut value = blst_fp::default();
+        for (limb, chunk) in value.l.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().expect("8-byte chunk"));
+        }
+        Ok(FpElement { value })
+    }
+    
+    /// Additive inverse
+    #[wasm_bindgen]
+    pub fn neg(&self) -> FpElement {
+        let mut result = blst_fp::default();
+        unsafe {
+            blst::blst_fp_cneg(&mut result, &self.value, true);
+        }
+        FpElement { value: result }
+    }
+    
+    /// A square root, or `undefined` for a non-residue; the other root is its `neg()`
+    #[wasm_bindgen]
+    pub fn sqrt(&self) -> Option<FpElement> {
+        let mut root = blst_fp::default();
+        let is_square = unsafe { blst::blst_fp_sqrt(&mut root, &self.value) };
+        is_square.then_some(FpElement { value: root })
+    }
+    
+    /// Legendre symbol: 1 for a nonzero square, -1 for a non-square, 0 for zero
+    #[wasm_bindgen]
+    pub fn legendre(&self) -> i32 {
+        if self.is_zero() {
+            return 0;
+        }
+        
+        // Euler's criterion: a^((p-1)/2) is 1 for squares and p-1 otherwise
+        let symbol = self.pow_be(&P_MINUS_1_OVER_2);
+        if symbol.value.l == FpElement::one().value.l { 1 } else { -1 }
+    }
+}
+
+/// (p - 1) / 2 as big-endian bytes, the Euler criterion exponent
+const P_MINUS_1_OVER_2: [u8; 48] = [
+    0x0d, 0x00, 0x88, 0xf5, 0x1c, 0xbf, 0xf3, 0x4d, 0x25, 0x8d, 0xd3, 0xdb,
+    0x21, 0xa5, 0xd6, 0x6b, 0xb2, 0x3b, 0xa5, 0xc2, 0x79, 0xc2, 0x89, 0x5f,
+    0xb3, 0x98, 0x69, 0x50, 0x7b, 0x58, 0x7b, 0x12, 0x0f, 0x55, 0xff, 0xff,
+    0x58, 0xa9, 0xff, 0xff, 0xdc, 0xff, 0x7f, 0xff, 0xff, 0xff, 0xd5, 0x55,
+];
+
+impl FpElement {
+    /// Both square roots `(r, p - r)` of a quadratic residue, or `None` for a non-residue
+    ///
+    /// The second root is always the negation of the first (both are zero for zero); pick
+    /// between them by parity or sign as the encoding requires.
+    pub fn sqrt_both(&self) -> Option<(FpElement, FpElement)> {
+        self.sqrt().map(|root| (root, root.neg()))
+    }
+    
+    fn random_with(mut fill: impl FnMut(&mut [u8]) -> Result<(), CryptoError>) -> Result<FpElement, CryptoError> {
         let mut bytes = [0u8; 48];
+        fill(&mut bytes)?;
+        
+        let mut value = blst_fp::default();
         unsafe {
-            blst::blst_bendian_from_fp(bytes.as_mut_ptr(), &self.value);
+            blst::blst_fp_from_bendian(&mut value, bytes.as_ptr());
         }
-        hex::encode(bytes)
+        
+        Ok(FpElement { value })
+    }
+    
+    /// The multiplicative identity
+    pub(crate) fn one() -> FpElement {
+        let mut value = blst_fp::default();
+        unsafe {
+            blst::blst_fp_from_uint64(&mut value, [1u64, 0, 0, 0, 0, 0].as_ptr());
+        }
+        FpElement { value }
+    }
+    
+    /// Square-and-multiply exponentiation by a big-endian exponent
+    pub(crate) fn pow_be(&self, exponent: &[u8]) -> FpElement {
+        let mut result = FpElement::one().value;
+        for byte in exponent {
+            for bit in (0..8).rev() {
+                unsafe {
+                    blst::blst_fp_sqr(&mut result, &result);
+                    if (byte >> bit) & 1 == 1 {
+                        blst::blst_fp_mul(&mut result, &result, &self.value);
+                    }
+                }
+            }
+        }
+        FpElement { value: result }
     }
 }
 
 #[wasm_bindgen]
 impl Fp2Element {
-    /// Create a new Fp2 element from two Fp elements
+    /// Create the Fp2 element c0 + c1·u
     #[wasm_bindgen(constructor)]
     pub fn new(c0: &FpElement, c1: &FpElement) -> Fp2Element {
-        let mut value = blst_fp2::default();
-        unsafe {
-            blst::blst_fp_add(&mut value.fp[0], &c0.value, &c0.value); // Simple init
-            blst::blst_fp_add(&mut value.fp[1], &c1.value, &c1.value); // Simple init
-        }
-        Fp2Element { value }
+        Fp2Element { value: blst_fp2 { fp: [c0.value, c1.value] } }
     }
     
     /// Add two Fp2 elements
@@ -229,6 +402,54 @@ impl Fp2Element {
         Fp2Element { value: result }
     }
     
+    /// Square an Fp2 element
+    #[wasm_bindgen]
+    pub fn square(&self) -> Fp2Element {
+        let mut result = blst_fp2::default();
+        unsafe {
+            blst::blst_fp2_sqr(&mut result, &self.value);
+        }
+        Fp2Element { value: result }
+    }
+    
+    /// Raise to a big-endian hex exponent by square-and-multiply (variable time in the exponent)
+    #[wasm_bindgen]
+    pub fn pow(&self, exponent_hex: &str) -> Result<Fp2Element, CryptoError> {
+        let exponent = hex::decode(exponent_hex)
+            .map_err(|e| CryptoError::InvalidHex(e.to_string()))?;
+        
+        let mut result = Fp2Element::one();
+        for byte in exponent {
+            for bit in (0..8).rev() {
+                result = result.square();
+                if (byte >> bit) & 1 == 1 {
+                    result = result.mul(self);
+                }
+            }
+        }
+        Ok(result)
+    }
+    
+    /// Decode 96 bytes: c0 then c1, each as 48 big-endian bytes
+    #[wasm_bindgen]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Fp2Element, CryptoError> {
+        if bytes.len() != 96 {
+            return Err(CryptoError::WrongLength { expected: 96, got: bytes.len() });
+        }
+        
+        let c0 = FpElement::from_bytes(&bytes[..48])?;
+        let c1 = FpElement::from_bytes(&bytes[48..])?;
+        Ok(Fp2Element { value: blst_fp2 { fp: [c0.value, c1.value] } })
+    }
+    
+    /// Decode the hex form of `from_bytes` (192 hex digits, c0 first)
+    #[wasm_bindgen]
+    pub fn from_hex(hex_string: &str) -> Result<Fp2Element, CryptoError> {
+        let bytes = hex::decode(hex_string)
+            .map_err(|e| CryptoError::InvalidHex(e.to_string()))?;
+        Fp2Element::from_bytes(&bytes)
+    }
+    
     /// Get the real component (c0)
     #[wasm_bindgen]
     pub fn c0(&self) -> FpElement {
@@ -242,6 +463,13 @@ impl Fp2Element {
     }
 }
 
+impl Fp2Element {
+    /// The multiplicative identity
+    pub(crate) fn one() -> Fp2Element {
+        Fp2Element { value: blst_fp2 { fp: [FpElement::one().value, blst_fp::default()] } }
+    }
+}
+
 #[wasm_bindgen]
 impl G1Point {
     /// Create identity point (point at infinity)
@@ -254,19 +482,22 @@ impl G1Point {
         G1Point { point }
     }
     
-    /// Create a random G1 point
+    /// The standard G1 generator
     #[wasm_bindgen]
-    pub fn random() -> G1Point {
-        let mut scalar_bytes = [0u8; 32];
-        getrandom::getrandom(&mut scalar_bytes).unwrap();
-        let mut point = blst_p1::default();
-        
-        unsafe {
-            let generator = blst_p1::default();
-            blst::blst_p1_mult(&mut point, &generator, scalar_bytes.as_ptr(), 256);
-        }
-        
-        G1Point { point }
+    pub fn generator() -> G1Point {
+        G1Point { point: unsafe { *blst::blst_p1_generator() } }
+    }
+    
+    /// Create a random G1 point as a random multiple of the generator
+    #[wasm_bindgen]
+    pub fn random() -> Result<G1Point, CryptoError> {
+        G1Point::random_with(fill_random)
+    }
+    
+    /// Deterministic random multiple of the generator: the same seed always yields the same point
+    #[wasm_bindgen]
+    pub fn random_from_seed(seed: &[u8]) -> G1Point {
+        G1Point::random_with(seeded_fill(seed)).expect("seeded RNG cannot fail")
     }
     
     /// Add two G1 points
@@ -274,360 +505,4183 @@ impl G1Point {
     pub fn add(&self, other: &G1Point) -> G1Point {
         let mut result = blst_p1::default();
         unsafe {
-            blst::blst_p1_add(&mut result, &self.point, &other.point);
+            blst::blst_p1_add_or_double(&mut result, &self.point, &other.point);
         }
         G1Point { point: result }
     }
     
-    /// Scalar multiplication
+    /// Negate the point
     #[wasm_bindgen]
-    pub fn scalar_mul(&self, scalar: &str) -> Result<G1Point, JsValue> {
-        let bytes = hex::decode(scalar)
-            .map_err(|e| JsValue::from_str(&format!("Invalid scalar hex: {}", e)))?;
-        
-        if bytes.len() != 32 {
-            return Err(JsValue::from_str("Scalar must be 32 bytes"));
-        }
-        
-        let mut result = blst_p1::default();
+    pub fn negate(&self) -> G1Point {
+        let mut point = self.point;
         unsafe {
-            blst::blst_p1_mult(&mut result, &self.point, bytes.as_ptr(), 256);
+            blst::blst_p1_cneg(&mut point, true);
         }
-        
-        Ok(G1Point { point: result })
+        G1Point { point }
     }
     
-    /// Check if point is at infinity
+    /// Subtract another G1 point
     #[wasm_bindgen]
-    pub fn is_infinity(&self) -> bool {
-        let mut point = blst_p1_affine::default();
-        unsafe {
-            blst::blst_p1_to_affine(&mut point, &self.point);
-        }
-        // Check if x coordinate is all zeros
-        let mut x_bytes = [0u8; 48];
+    pub fn subtract(&self, other: &G1Point) -> G1Point {
+        self.add(&other.negate())
+    }
+    
+    /// Double the point
+    #[wasm_bindgen]
+    pub fn double(&self) -> G1Point {
+        let mut result = blst_p1::default();
         unsafe {
-            blst::blst_bendian_from_fp(x_bytes.as_mut_ptr(), &point.x);
+            blst::blst_p1_double(&mut result, &self.point);
         }
-        x_bytes.iter().all(|&b| b == 0)
+        G1Point { point: result }
     }
     
-    /// Get point coordinates as hex strings
+    /// Check whether two points are equal, regardless of projective representation
     #[wasm_bindgen]
-    pub fn get_coordinates(&self) -> JsValue {
-        let mut point = blst_p1_affine::default();
+    pub fn equals(&self, other: &G1Point) -> bool {
+        unsafe { blst::blst_p1_is_equal(&self.point, &other.point) }
+    }
+    
+    /// Scalar multiplication by a 32-byte big-endian scalar
+    ///
+    /// Breaking change: earlier releases passed the decoded bytes to blst unreversed, so the
+    /// hex was effectively read as little-endian. Callers that relied on that order should
+    /// switch to `scalar_mul_le(scalar, 256)`.
+    #[wasm_bindgen]
+    pub fn scalar_mul(&self, scalar: &str) -> Result<G1Point, CryptoError> {
+        let bytes = hex::decode(scalar)
+            .map_err(|e| CryptoError::InvalidHex(e.to_string()))?;
+        self.scalar_mul_bytes(&bytes)
+    }
+    
+    /// Scalar multiplication by a big-endian scalar, using only its low `nbits` bits
+    #[wasm_bindgen]
+    pub fn scalar_mul_bits(&self, scalar: &str, nbits: usize) -> Result<G1Point, CryptoError> {
+        let bytes = decode_scalar_hex(scalar, nbits)?;
+        self.scalar_mul_bytes_bits(&bytes, nbits)
+    }
+    
+    /// Scalar multiplication by a big-endian scalar given as raw bytes, the binary form of `scalar_mul`
+    #[wasm_bindgen]
+    pub fn scalar_mul_bytes(&self, scalar: &[u8]) -> Result<G1Point, CryptoError> {
+        check_scalar_len(scalar)?;
+        self.scalar_mul_bytes_bits(scalar, 256)
+    }
+    
+    /// Scalar multiplication by a little-endian scalar, using only its low `nbits` bits
+    #[wasm_bindgen]
+    pub fn scalar_mul_le(&self, scalar: &str, nbits: usize) -> Result<G1Point, CryptoError> {
+        let bytes = decode_scalar_hex(scalar, nbits)?;
+        Ok(self.mult_le(&bytes, nbits))
+    }
+    
+    /// Scalar multiplication by a field-reduced Scalar
+    #[wasm_bindgen]
+    pub fn scalar_mul_fr(&self, scalar: &Scalar) -> G1Point {
+        let mut result = blst_p1::default();
         unsafe {
-            blst::blst_p1_to_affine(&mut point, &self.point);
+            blst::blst_p1_mult(&mut result, &self.point, scalar.value.b.as_ptr(), 255);
         }
+        G1Point { point: result }
+    }
+    
+    /// Compute `s1·self + s2·other` with one shared double-and-add pass (Shamir's trick)
+    ///
+    /// Each scalar is first split into two ~128-bit halves via the GLV endomorphism, so the
+    /// interleaved loop runs over four points but only half as many doublings.
+    /// Variable-time in the scalars, so only use it with public values such as in verification.
+    #[wasm_bindgen]
+    pub fn mul_add(&self, s1: &Scalar, other: &G1Point, s2: &Scalar) -> G1Point {
+        let points = [*self, self.endomorphism(), *other, other.endomorphism()];
+        let affines = g1_to_affines(&points);
         
-        let mut x_bytes = [0u8; 48];
-        let mut y_bytes = [0u8; 48];
-        
-        unsafe {
-            blst::blst_bendian_from_fp(x_bytes.as_mut_ptr(), &point.x);
-            blst::blst_bendian_from_fp(y_bytes.as_mut_ptr(), &point.y);
+        let mut scalars = Vec::with_capacity(4 * GLV_HALF_BYTES);
+        for s in [s1, s2] {
+            let (k1, k2) = glv_split(s);
+            scalars.extend_from_slice(&k1);
+            scalars.extend_from_slice(&k2);
         }
         
-        let coords = serde_wasm_bindgen::to_value(&serde_json::json!({
-            "x": hex::encode(x_bytes),
-            "y": hex::encode(y_bytes),
-            "infinity": self.is_infinity()
-        })).unwrap();
-        
-        coords
+        G1Point { point: g1_pippenger_serial_bits(&affines, &scalars, GLV_HALF_BITS) }
     }
-}
-
-#[wasm_bindgen]
-impl G2Point {
-    /// Create identity point (point at infinity)
-    #[wasm_bindgen(constructor)]
-    pub fn identity() -> G2Point {
-        let mut point = blst_p2::default();
-        unsafe {
-            blst::blst_p2_add(&mut point, &point, &blst_p2::default());
-        }
-        G2Point { point }
+    
+    /// Check if point is at infinity
+    #[wasm_bindgen]
+    pub fn is_infinity(&self) -> bool {
+        unsafe { blst::blst_p1_is_inf(&self.point) }
     }
     
-    /// Create a random G2 point
+    /// Check that the point satisfies the curve equation
     #[wasm_bindgen]
-    pub fn random() -> G2Point {
-        let mut scalar_bytes = [0u8; 32];
-        getrandom::getrandom(&mut scalar_bytes).unwrap();
-        let mut point = blst_p2::default();
-        
+    pub fn is_on_curve(&self) -> bool {
+        unsafe { blst::blst_p1_on_curve(&self.point) }
+    }
+    
+    /// Check that the point lies in the prime-order subgroup
+    #[wasm_bindgen]
+    pub fn is_in_subgroup(&self) -> bool {
+        unsafe { blst::blst_p1_in_g1(&self.point) }
+    }
+    
+    /// Whether y is the larger of the two roots, i.e. the sign flag set in compressed form
+    #[wasm_bindgen]
+    pub fn y_is_lexicographically_largest(&self) -> bool {
+        let mut compressed = [0u8; 48];
         unsafe {
-            let generator = blst_p2::default();
-            blst::blst_p2_mult(&mut point, &generator, scalar_bytes.as_ptr(), 256);
+            blst::blst_p1_compress(compressed.as_mut_ptr(), &self.point);
         }
-        
-        G2Point { point }
+        // Bit 5 of the first byte is the sort flag in the ZCash encoding
+        compressed[0] & 0x20 != 0
     }
     
-    /// Add two G2 points
+    /// Compressed 48-byte encoding (ZCash format: x with flag bits in the top byte)
     #[wasm_bindgen]
-    pub fn add(&self, other: &G2Point) -> G2Point {
-        let mut result = blst_p2::default();
+    pub fn compress(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; 48];
         unsafe {
-            blst::blst_p2_add(&mut result, &self.point, &other.point);
+            blst::blst_p1_compress(bytes.as_mut_ptr(), &self.point);
         }
-        G2Point { point: result }
+        bytes
     }
     
-    /// Scalar multiplication
+    /// Uncompressed 96-byte encoding (ZCash format: x || y with flag bits in the top byte)
     #[wasm_bindgen]
-    pub fn scalar_mul(&self, scalar: &str) -> Result<G2Point, JsValue> {
-        let bytes = hex::decode(scalar)
-            .map_err(|e| JsValue::from_str(&format!("Invalid scalar hex: {}", e)))?;
-        
-        if bytes.len() != 32 {
-            return Err(JsValue::from_str("Scalar must be 32 bytes"));
-        }
-        
-        let mut result = blst_p2::default();
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; 96];
         unsafe {
-            blst::blst_p2_mult(&mut result, &self.point, bytes.as_ptr(), 256);
+            blst::blst_p1_serialize(bytes.as_mut_ptr(), &self.point);
         }
-        
-        Ok(G2Point { point: result })
+        bytes
     }
     
-    /// Check if point is at infinity
+    /// Encode as EIP-2537 bytes: x || y, each zero-padded to 64 bytes (128 bytes total)
     #[wasm_bindgen]
-    pub fn is_infinity(&self) -> bool {
-        let mut point = blst_p2_affine::default();
+    pub fn to_eip2537_bytes(&self) -> Vec<u8> {
+        let mut point = blst_p1_affine::default();
         unsafe {
-            blst::blst_p2_to_affine(&mut point, &self.point);
+            blst::blst_p1_to_affine(&mut point, &self.point);
         }
-        // Check if x coordinate's first component is all zeros
-        let mut x_bytes = [0u8; 48];
-        unsafe {
-            blst::blst_bendian_from_fp(x_bytes.as_mut_ptr(), &point.x.fp[0]);
+        
+        let mut bytes = vec![0u8; 128];
+        if !self.is_infinity() {
+            fp_to_eip2537(&point.x, &mut bytes[0..64]);
+            fp_to_eip2537(&point.y, &mut bytes[64..128]);
         }
-        x_bytes.iter().all(|&b| b == 0)
+        bytes
     }
     
-    /// Get point coordinates as hex strings
+    /// Decode EIP-2537 bytes, checking padding, curve and subgroup membership
     #[wasm_bindgen]
-    pub fn get_coordinates(&self) -> JsValue {
-        let mut point = blst_p2_affine::default();
-        unsafe {
-            blst::blst_p2_to_affine(&mut point, &self.point);
+    pub fn from_eip2537_bytes(bytes: &[u8]) -> Result<G1Point, CryptoError> {
+        if bytes.len() != 128 {
+            return Err(CryptoError::WrongLength { expected: 128, got: bytes.len() });
         }
         
-        let mut x_c0_bytes = [0u8; 48];
-        let mut x_c1_bytes = [0u8; 48];
-        let mut y_c0_bytes = [0u8; 48];
-        let mut y_c1_bytes = [0u8; 48];
+        let affine = blst_p1_affine {
+            x: fp_from_eip2537(&bytes[0..64])?,
+            y: fp_from_eip2537(&bytes[64..128])?,
+        };
         
-        unsafe {
-            blst::blst_bendian_from_fp(x_c0_bytes.as_mut_ptr(), &point.x.fp[0]);
-            blst::blst_bendian_from_fp(x_c1_bytes.as_mut_ptr(), &point.x.fp[1]);
-            blst::blst_bendian_from_fp(y_c0_bytes.as_mut_ptr(), &point.y.fp[0]);
-            blst::blst_bendian_from_fp(y_c1_bytes.as_mut_ptr(), &point.y.fp[1]);
+        G1Point::from_affine_checked(&affine)
+    }
+    
+    /// Decode the 48-byte compressed encoding, checking curve and subgroup membership
+    #[wasm_bindgen]
+    pub fn uncompress(bytes: &[u8]) -> Result<G1Point, CryptoError> {
+        if bytes.len() != 48 {
+            return Err(CryptoError::WrongLength { expected: 48, got: bytes.len() });
         }
         
-        let coords = serde_wasm_bindgen::to_value(&serde_json::json!({
-            "x": {
-                "c0": hex::encode(x_c0_bytes),
-                "c1": hex::encode(x_c1_bytes)
-            },
-            "y": {
-                "c0": hex::encode(y_c0_bytes),
-                "c1": hex::encode(y_c1_bytes)
-            },
-            "infinity": self.is_infinity()
-        })).unwrap();
-        
-        coords
+        let mut affine = blst_p1_affine::default();
+        let err = unsafe { blst::blst_p1_uncompress(&mut affine, bytes.as_ptr()) };
+        check_decode(err)?;
+        G1Point::from_affine_checked(&affine)
     }
-}
-
-/// Pairing operation between G1 and G2 points
-#[wasm_bindgen]
-pub fn pairing(g1: &G1Point, g2: &G2Point) -> JsValue {
-    use blst::blst_fp12;
-    let mut result = blst_fp12::default();
     
-    // Convert to affine points for pairing
-    let mut g1_affine = blst_p1_affine::default();
-    let mut g2_affine = blst_p2_affine::default();
+    /// Decode the 96-byte uncompressed encoding, checking curve and subgroup membership
+    #[wasm_bindgen]
+    pub fn deserialize(bytes: &[u8]) -> Result<G1Point, CryptoError> {
+        if bytes.len() != 96 {
+            return Err(CryptoError::WrongLength { expected: 96, got: bytes.len() });
+        }
+        
+        let mut affine = blst_p1_affine::default();
+        let err = unsafe { blst::blst_p1_deserialize(&mut affine, bytes.as_ptr()) };
+        check_decode(err)?;
+        G1Point::from_affine_checked(&affine)
+    }
     
-    unsafe {
-        blst::blst_p1_to_affine(&mut g1_affine, &g1.point);
-        blst::blst_p2_to_affine(&mut g2_affine, &g2.point);
-        blst::blst_miller_loop(&mut result, &g2_affine, &g1_affine);
-        blst::blst_final_exp(&mut result, &result);
+    /// Get point coordinates as hex strings
+    #[wasm_bindgen]
+    pub fn get_coordinates(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.coordinates()).unwrap()
     }
     
-    serde_wasm_bindgen::to_value(&serde_json::json!({
-        "pairing_computed": true,
-        "message": "Pairing result computed successfully"
-    })).unwrap()
-}
-
-/// Hash functions for cryptographic operations
-#[wasm_bindgen]
-pub struct HashFunctions;
-
-#[wasm_bindgen]
-impl HashFunctions {
-    /// SHA-256 hash
+    /// Build a point from big-endian hex affine coordinates, checking curve and subgroup membership
+    ///
+    /// As in `get_coordinates`, x = y = 0 denotes the point at infinity.
     #[wasm_bindgen]
-    pub fn sha256(data: &[u8]) -> HashResult {
-        use sha2::{Sha256, Digest};
-        let mut hasher = Sha256::new();
-        hasher.update(data);
-        let result = hasher.finalize();
-        
-        HashResult {
-            hash: result.to_vec(),
-            algorithm: "SHA-256".to_string(),
-        }
+    pub fn from_coordinates(x_hex: &str, y_hex: &str) -> Result<G1Point, CryptoError> {
+        let affine = blst_p1_affine {
+            x: fp_from_hex_canonical(x_hex)?,
+            y: fp_from_hex_canonical(y_hex)?,
+        };
+        G1Point::from_affine_checked(&affine)
     }
     
-    /// SHA-512 hash
+    /// Like `from_coordinates` but without the subgroup check, for points that still need `clear_cofactor`
     #[wasm_bindgen]
-    pub fn sha512(data: &[u8]) -> HashResult {
-        use sha2::{Sha512, Digest};
-        let mut hasher = Sha512::new();
-        hasher.update(data);
-        let result = hasher.finalize();
-        
-        HashResult {
-            hash: result.to_vec(),
-            algorithm: "SHA-512".to_string(),
-        }
+    pub fn from_curve_coordinates(x_hex: &str, y_hex: &str) -> Result<G1Point, CryptoError> {
+        let affine = blst_p1_affine {
+            x: fp_from_hex_canonical(x_hex)?,
+            y: fp_from_hex_canonical(y_hex)?,
+        };
+        G1Point::from_affine_on_curve(&affine)
     }
     
-    /// BLAKE2b hash
+    /// Map any curve point into the prime-order subgroup by multiplying by h_eff = 1 - z
+    ///
+    /// This is RFC 9380's `clear_cofactor` for G1, so points already in the subgroup are
+    /// scaled by h_eff too rather than returned unchanged.
     #[wasm_bindgen]
-    pub fn blake2b(data: &[u8]) -> HashResult {
-        use blake2::{Blake2b512, Digest};
-        let mut hasher = Blake2b512::new();
-        hasher.update(data);
-        let result = hasher.finalize();
-        
-        HashResult {
-            hash: result.to_vec(),
-            algorithm: "BLAKE2b".to_string(),
-        }
+    pub fn clear_cofactor(&self) -> G1Point {
+        self.mult_le(&G1_H_EFF.to_le_bytes(), 64)
     }
     
-    /// Convert hash to hex string
+    /// Export as `{x, y, infinity, compressed}` with hex strings
     #[wasm_bindgen]
-    pub fn to_hex(hash: &HashResult) -> String {
-        hex::encode(&hash.hash)
+    pub fn to_json(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.to_json_value()).unwrap()
+    }
+    
+    /// Import from `to_json` or `get_coordinates` output, checking curve and subgroup membership
+    #[wasm_bindgen]
+    pub fn from_json(value: JsValue) -> Result<G1Point, CryptoError> {
+        let value: serde_json::Value = serde_wasm_bindgen::from_value(value)
+            .map_err(|e| CryptoError::Serialization(e.to_string()))?;
+        G1Point::from_json_value(&value)
     }
 }
 
-/// Performance benchmarking utilities
-#[wasm_bindgen]
-pub struct Benchmarks;
-
-#[wasm_bindgen]
-impl Benchmarks {
-    /// Run performance benchmarks
-    #[wasm_bindgen]
-    pub fn run_benchmarks() -> Vec<JsValue> {
-        let mut results = Vec::new();
+impl G1Point {
+    fn random_with(mut fill: impl FnMut(&mut [u8]) -> Result<(), CryptoError>) -> Result<G1Point, CryptoError> {
+        let mut scalar_bytes = [0u8; 32];
+        fill(&mut scalar_bytes)?;
+        let mut point = blst_p1::default();
         
-        // Benchmark field multiplication
-        let start_wasm = performance::now();
-        let a = FpElement::random();
-        let b = FpElement::random();
-        for _ in 0..1000 {
-            let _ = a.mul(&b);
+        unsafe {
+            blst::blst_p1_mult(&mut point, blst::blst_p1_generator(), scalar_bytes.as_ptr(), 256);
         }
-        let wasm_time = (performance::now() - start_wasm) * 1000.0;
         
-        // Mock JS comparison time (would be much slower)
-        let js_time = wasm_time * 3.0; // Assume JS is 3x slower
-        
-        let metrics = PerformanceMetrics {
-            operation: "Field Multiplication".to_string(),
-            wasm_time_us: wasm_time as u64,
-            js_time_us: js_time as u64,
-            speedup: js_time / wasm_time,
-            memory_used_kb: 1024,
+        Ok(G1Point { point })
+    }
+    
+    /// Affine coordinates as a `{x, y, infinity}` object of hex strings
+    pub fn coordinates(&self) -> serde_json::Value {
+        let mut point = blst_p1_affine::default();
+        unsafe {
+            blst::blst_p1_to_affine(&mut point, &self.point);
+        }
+        g1_affine_json(&point, self.is_infinity())
+    }
+    
+    /// Rust-side counterpart of `to_json`
+    pub fn to_json_value(&self) -> serde_json::Value {
+        let mut value = self.coordinates();
+        value["compressed"] = hex::encode(self.compress()).into();
+        value
+    }
+    
+    /// Rust-side counterpart of `from_json`
+    ///
+    /// `x` and `y` are required. `infinity` and `compressed` are optional but must agree with
+    /// the decoded point when present.
+    pub fn from_json_value(value: &serde_json::Value) -> Result<G1Point, CryptoError> {
+        let field = |name: &str| {
+            value
+                .get(name)
+                .ok_or_else(|| CryptoError::Serialization(format!("missing field `{}`", name)))
+        };
+        let hex_field = |name: &str| {
+            field(name)?
+                .as_str()
+                .ok_or_else(|| CryptoError::Serialization(format!("field `{}` must be a hex string", name)))
         };
         
-        results.push(serde_wasm_bindgen::to_value(&metrics).unwrap());
+        let point = G1Point::from_coordinates(hex_field("x")?, hex_field("y")?)?;
         
-        results
+        if value.get("infinity").is_some() {
+            let infinity = field("infinity")?
+                .as_bool()
+                .ok_or_else(|| CryptoError::Serialization("field `infinity` must be a boolean".to_string()))?;
+            if infinity != point.is_infinity() {
+                return Err(CryptoError::InvalidEncoding);
+            }
+        }
+        if value.get("compressed").is_some() {
+            let compressed = hex::decode(hex_field("compressed")?)
+                .map_err(|e| CryptoError::InvalidHex(e.to_string()))?;
+            if compressed != point.compress() {
+                return Err(CryptoError::InvalidEncoding);
+            }
+        }
+        
+        Ok(point)
     }
     
-    /// Get memory usage info
-    #[wasm_bindgen]
-    pub fn get_memory_usage() -> JsValue {
-        let metrics = serde_json::json!({
-            "heap_used_kb": 512,
-            "heap_total_kb": 1024,
-            "memory_pages": 64
-        });
+    /// Convert an affine point to a G1Point, rejecting points off the curve or outside the subgroup
+    pub(crate) fn from_affine_checked(affine: &blst_p1_affine) -> Result<G1Point, CryptoError> {
+        let point = G1Point::from_affine_on_curve(affine)?;
+        if !unsafe { blst::blst_p1_affine_in_g1(affine) } {
+            return Err(CryptoError::NotInSubgroup);
+        }
+        Ok(point)
+    }
+    
+    /// Convert an affine point to a G1Point, rejecting points off the curve but not outside the subgroup
+    fn from_affine_on_curve(affine: &blst_p1_affine) -> Result<G1Point, CryptoError> {
+        if !unsafe { blst::blst_p1_affine_on_curve(affine) } {
+            return Err(CryptoError::NotOnCurve);
+        }
         
-        serde_wasm_bindgen::to_value(&metrics).unwrap()
+        let mut point = blst_p1::default();
+        unsafe {
+            blst::blst_p1_from_affine(&mut point, affine);
+        }
+        Ok(G1Point { point })
+    }
+    
+    /// The GLV endomorphism φ(x, y) = (βx, y), which acts on G1 as multiplication by λ
+    fn endomorphism(&self) -> G1Point {
+        let mut beta = blst_fp::default();
+        let mut point = self.point;
+        unsafe {
+            blst::blst_fp_from_bendian(&mut beta, GLV_BETA.as_ptr());
+            // Jacobian X scales like affine x, so Y and Z are untouched
+            blst::blst_fp_mul(&mut point.x, &point.x, &beta);
+        }
+        G1Point { point }
+    }
+    
+    /// Multiply by the low `nbits` bits of a big-endian scalar
+    fn scalar_mul_bytes_bits(&self, scalar: &[u8], nbits: usize) -> Result<G1Point, CryptoError> {
+        check_scalar_bits(scalar, nbits)?;
+        let mut bytes = scalar.to_vec();
+        bytes.reverse();
+        Ok(self.mult_le(&bytes, nbits))
+    }
+    
+    /// Multiply by a little-endian scalar of `nbits` bits (blst's native order)
+    pub(crate) fn mult_le(&self, scalar_le: &[u8], nbits: usize) -> G1Point {
+        let mut result = blst_p1::default();
+        unsafe {
+            blst::blst_p1_mult(&mut result, &self.point, scalar_le.as_ptr(), nbits);
+        }
+        G1Point { point: result }
     }
 }
 
-#[wasm_bindgen]
-pub struct CryptoModule;
+// Points serialize through their compressed encoding: a hex string in human-readable
+// formats such as JSON, raw bytes otherwise. Deserializing checks curve and subgroup membership.
 
-#[wasm_bindgen]
-impl CryptoModule {
-    /// Initialize the crypto module
-    #[wasm_bindgen(constructor)]
-    pub fn new() -> CryptoModule {
-        CryptoModule
+impl Serialize for G1Point {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_compressed(&self.compress(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for G1Point {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<G1Point, D::Error> {
+        let bytes = deserialize_compressed(deserializer)?;
+        G1Point::uncompress(&bytes).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for G2Point {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_compressed(&self.compress(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for G2Point {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<G2Point, D::Error> {
+        let bytes = deserialize_compressed(deserializer)?;
+        G2Point::uncompress(&bytes).map_err(serde::de::Error::custom)
+    }
+}
+
+fn serialize_compressed<S: serde::Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&hex::encode(bytes))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+fn deserialize_compressed<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(CompressedVisitor)
+    } else {
+        deserializer.deserialize_bytes(CompressedVisitor)
+    }
+}
+
+struct CompressedVisitor;
+
+impl<'de> serde::de::Visitor<'de> for CompressedVisitor {
+    type Value = Vec<u8>;
+    
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a compressed curve point as a hex string or bytes")
     }
     
-    /// Get module version
-    #[wasm_bindgen]
-    pub fn version() -> String {
-        "1.0.0".to_string()
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Vec<u8>, E> {
+        hex::decode(v).map_err(E::custom)
     }
     
-    /// Check if all cryptographic functions are working
-    #[wasm_bindgen]
-    pub fn self_test() -> Result<JsValue, JsValue> {
-        // Test basic field operations
-        let a = FpElement::random();
-        let b = FpElement::random();
-        let c = a.mul(&b);
-        let d = a.add(&b);
-        
-        if c.is_zero() || d.is_zero() {
-            return Err(JsValue::from_str("Field operations failed"));
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(v.to_vec())
+    }
+    
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(b) = seq.next_element()? {
+            bytes.push(b);
+        }
+        Ok(bytes)
+    }
+}
+
+/// Fill `buf` from the platform RNG (`crypto.getRandomValues` under WASM)
+#[cfg(not(feature = "failing-rng"))]
+fn fill_random(buf: &mut [u8]) -> Result<(), CryptoError> {
+    getrandom::getrandom(buf).map_err(|e| CryptoError::RngUnavailable(e.to_string()))
+}
+
+/// Stand-in RNG that always fails, for exercising error paths in tests
+#[cfg(feature = "failing-rng")]
+fn fill_random(_buf: &mut [u8]) -> Result<(), CryptoError> {
+    Err(CryptoError::RngUnavailable("RNG disabled by the failing-rng feature".to_string()))
+}
+
+/// Byte source for the `random_from_seed` constructors: ChaCha20 keyed by SHA-256(seed)
+///
+/// Only for reproducible tests and fuzzing; a low-entropy seed gives predictable values.
+fn seeded_fill(seed: &[u8]) -> impl FnMut(&mut [u8]) -> Result<(), CryptoError> {
+    use rand_chacha::rand_core::{RngCore, SeedableRng};
+    use sha2::Digest;
+    
+    let mut rng = rand_chacha::ChaCha20Rng::from_seed(sha2::Sha256::digest(seed).into());
+    move |buf| {
+        rng.fill_bytes(buf);
+        Ok(())
+    }
+}
+
+/// Decode a hex scalar and check it holds at least `nbits` bits
+fn decode_scalar_hex(scalar: &str, nbits: usize) -> Result<Vec<u8>, CryptoError> {
+    let bytes = hex::decode(scalar)
+        .map_err(|e| CryptoError::InvalidHex(e.to_string()))?;
+    check_scalar_bits(&bytes, nbits)?;
+    Ok(bytes)
+}
+
+/// Check a raw scalar is exactly 32 bytes, as `scalar_mul` and `scalar_mul_bytes` require
+fn check_scalar_len(bytes: &[u8]) -> Result<(), CryptoError> {
+    if bytes.len() != 32 {
+        return Err(CryptoError::WrongLength { expected: 32, got: bytes.len() });
+    }
+    Ok(())
+}
+
+/// Check a raw scalar holds at least `nbits` bits
+fn check_scalar_bits(bytes: &[u8], nbits: usize) -> Result<(), CryptoError> {
+    if nbits == 0 || nbits > bytes.len() * 8 {
+        return Err(CryptoError::InvalidParameter(format!(
+            "bit count must be between 1 and {} for a {}-byte scalar",
+            bytes.len() * 8,
+            bytes.len()
+        )));
+    }
+    Ok(())
+}
+
+impl G2Point {
+    /// Affine coordinates as `{x: {c0, c1}, y: {c0, c1}, infinity}` with hex strings
+    pub fn coordinates(&self) -> serde_json::Value {
+        let mut point = blst_p2_affine::default();
+        unsafe {
+            blst::blst_p2_to_affine(&mut point, &self.point);
         }
         
-        // Test point operations
-        let p1 = G1Point::random();
-        let p2 = G1Point::random();
-        let p3 = p1.add(&p2);
+        serde_json::json!({
+            "x": {
+                "c0": fp_to_hex(&point.x.fp[0]),
+                "c1": fp_to_hex(&point.x.fp[1])
+            },
+            "y": {
+                "c0": fp_to_hex(&point.y.fp[0]),
+                "c1": fp_to_hex(&point.y.fp[1])
+            },
+            "infinity": self.is_infinity()
+        })
+    }
+    
+    fn random_with(mut fill: impl FnMut(&mut [u8]) -> Result<(), CryptoError>) -> Result<G2Point, CryptoError> {
+        let mut scalar_bytes = [0u8; 32];
+        fill(&mut scalar_bytes)?;
+        let mut point = blst_p2::default();
         
-        if p3.is_infinity() {
-            return Err(JsValue::from_str("Point operations failed"));
+        unsafe {
+            blst::blst_p2_mult(&mut point, blst::blst_p2_generator(), scalar_bytes.as_ptr(), 256);
         }
         
-        // Test hash functions
-        let hash_result = HashFunctions::sha256(b"test data");
-        if hash_result.hash.is_empty() {
-            return Err(JsValue::from_str("Hash functions failed"));
+        Ok(G2Point { point })
+    }
+    
+    /// Convert an affine point to a G2Point, rejecting points off the curve or outside the subgroup
+    pub(crate) fn from_affine_checked(affine: &blst_p2_affine) -> Result<G2Point, CryptoError> {
+        let point = G2Point::from_affine_on_curve(affine)?;
+        if !unsafe { blst::blst_p2_affine_in_g2(affine) } {
+            return Err(CryptoError::NotInSubgroup);
+        }
+        Ok(point)
+    }
+    
+    /// Convert an affine point to a G2Point, rejecting points off the curve but not outside the subgroup
+    fn from_affine_on_curve(affine: &blst_p2_affine) -> Result<G2Point, CryptoError> {
+        if !unsafe { blst::blst_p2_affine_on_curve(affine) } {
+            return Err(CryptoError::NotOnCurve);
         }
         
-        Ok(serde_wasm_bindgen::to_value(&serde_json::json!({
-            "status": "success",
-            "message": "All cryptographic operations working correctly"
-        })).unwrap())
+        let mut point = blst_p2::default();
+        unsafe {
+            blst::blst_p2_from_affine(&mut point, affine);
+        }
+        Ok(G2Point { point })
+    }
+    
+    /// The untwist-Frobenius-twist endomorphism ψ(x, y) = (conj(x)·c_x, conj(y)·c_y)
+    fn psi(&self) -> G2Point {
+        let mut coeff_x = blst_fp2::default();
+        let mut coeff_y = blst_fp2::default();
+        let mut point = self.point;
+        unsafe {
+            blst::blst_fp_from_bendian(&mut coeff_x.fp[1], PSI_COEFF_X_C1.as_ptr());
+            blst::blst_fp_from_bendian(&mut coeff_y.fp[0], PSI_COEFF_Y[0].as_ptr());
+            blst::blst_fp_from_bendian(&mut coeff_y.fp[1], PSI_COEFF_Y[1].as_ptr());
+            
+            // Conjugation is a field automorphism, so it applies to Jacobian X, Y and Z alike
+            for coord in [&mut point.x, &mut point.y, &mut point.z] {
+                blst::blst_fp_cneg(&mut coord.fp[1], &coord.fp[1], true);
+            }
+            blst::blst_fp2_mul(&mut point.x, &point.x, &coeff_x);
+            blst::blst_fp2_mul(&mut point.y, &point.y, &coeff_y);
+        }
+        G2Point { point }
+    }
+    
+    /// Multiply by z, the (negative) BLS parameter
+    fn mul_by_z(&self) -> G2Point {
+        let mut result = blst_p2::default();
+        unsafe {
+            blst::blst_p2_mult(&mut result, &self.point, BLS_Z_ABS.to_le_bytes().as_ptr(), 64);
+        }
+        G2Point { point: result }.negate()
     }
 }
 
-// Performance tracking
-mod performance {
-    use js_sys::Date;
+/// Map a blst decoding status to an error
+fn check_decode(err: blst::BLST_ERROR) -> Result<(), CryptoError> {
+    match err {
+        blst::BLST_ERROR::BLST_SUCCESS => Ok(()),
+        blst::BLST_ERROR::BLST_POINT_NOT_ON_CURVE => Err(CryptoError::NotOnCurve),
+        blst::BLST_ERROR::BLST_POINT_NOT_IN_GROUP => Err(CryptoError::NotInSubgroup),
+        _ => Err(CryptoError::InvalidEncoding),
+    }
+}
+
+/// Write a field element as a 64-byte EIP-2537 word (16 zero bytes, then 48 big-endian bytes)
+fn fp_to_eip2537(fp: &blst_fp, out: &mut [u8]) {
+    out[..16].fill(0);
+    unsafe {
+        blst::blst_bendian_from_fp(out[16..64].as_mut_ptr(), fp);
+    }
+}
+
+/// Canonical 48-byte big-endian encoding of a base field element
+fn fp_to_bytes(fp: &blst_fp) -> [u8; 48] {
+    let mut bytes = [0u8; 48];
+    unsafe {
+        blst::blst_bendian_from_fp(bytes.as_mut_ptr(), fp);
+    }
+    bytes
+}
+
+/// Hex-encode a base field element through a stack buffer, allocating only the returned string
+fn fp_to_hex(fp: &blst_fp) -> String {
+    let mut buf = [0u8; 96];
+    hex::encode_to_slice(fp_to_bytes(fp), &mut buf).expect("96 hex digits for 48 bytes");
+    String::from_utf8(buf.to_vec()).expect("hex digits are ASCII")
+}
+
+/// Parse 96 hex digits as a big-endian field element, rejecting values not below p
+fn fp_from_hex_canonical(hex_string: &str) -> Result<blst_fp, CryptoError> {
+    let bytes = hex::decode(hex_string)
+        .map_err(|e| CryptoError::InvalidHex(e.to_string()))?;
+    if bytes.len() != 48 {
+        return Err(CryptoError::WrongLength { expected: 48, got: bytes.len() });
+    }
     
-    pub fn now() -> f64 {
-        Date::now()
+    let mut fp = blst_fp::default();
+    unsafe {
+        blst::blst_fp_from_bendian(&mut fp, bytes.as_ptr());
+    }
+    if fp_to_bytes(&fp)[..] != bytes[..] {
+        return Err(CryptoError::NonCanonical("field element is not reduced modulo p"));
+    }
+    
+    Ok(fp)
+}
+
+/// Read a 64-byte EIP-2537 word, rejecting nonzero padding and values not below p
+fn fp_from_eip2537(word: &[u8]) -> Result<blst_fp, CryptoError> {
+    if word[..16].iter().any(|&b| b != 0) {
+        return Err(CryptoError::NonCanonical("EIP-2537 field element has nonzero padding"));
+    }
+    
+    let mut fp = blst_fp::default();
+    unsafe {
+        blst::blst_fp_from_bendian(&mut fp, word[16..64].as_ptr());
+    }
+    if fp_to_bytes(&fp)[..] != word[16..64] {
+        return Err(CryptoError::NonCanonical("EIP-2537 field element is not reduced modulo p"));
+    }
+    
+    Ok(fp)
+}
+
+#[wasm_bindgen]
+impl G2Point {
+    /// Create identity point (point at infinity)
+    #[wasm_bindgen(constructor)]
+    pub fn identity() -> G2Point {
+        let mut point = blst_p2::default();
+        unsafe {
+            blst::blst_p2_add(&mut point, &point, &blst_p2::default());
+        }
+        G2Point { point }
+    }
+    
+    /// The standard G2 generator
+    #[wasm_bindgen]
+    pub fn generator() -> G2Point {
+        G2Point { point: unsafe { *blst::blst_p2_generator() } }
+    }
+    
+    /// Create a random G2 point as a random multiple of the generator
+    #[wasm_bindgen]
+    pub fn random() -> Result<G2Point, CryptoError> {
+        G2Point::random_with(fill_random)
+    }
+    
+    /// Deterministic random multiple of the generator: the same seed always yields the same point
+    #[wasm_bindgen]
+    pub fn random_from_seed(seed: &[u8]) -> G2Point {
+        G2Point::random_with(seeded_fill(seed)).expect("seeded RNG cannot fail")
+    }
+    
+    /// Add two G2 points
+    #[wasm_bindgen]
+    pub fn add(&self, other: &G2Point) -> G2Point {
+        let mut result = blst_p2::default();
+        unsafe {
+            blst::blst_p2_add_or_double(&mut result, &self.point, &other.point);
+        }
+        G2Point { point: result }
+    }
+    
+    /// Negate the point
+    #[wasm_bindgen]
+    pub fn negate(&self) -> G2Point {
+        let mut point = self.point;
+        unsafe {
+            blst::blst_p2_cneg(&mut point, true);
+        }
+        G2Point { point }
+    }
+    
+    /// Subtract another G2 point
+    #[wasm_bindgen]
+    pub fn subtract(&self, other: &G2Point) -> G2Point {
+        self.add(&other.negate())
+    }
+    
+    /// Double the point
+    #[wasm_bindgen]
+    pub fn double(&self) -> G2Point {
+        let mut result = blst_p2::default();
+        unsafe {
+            blst::blst_p2_double(&mut result, &self.point);
+        }
+        G2Point { point: result }
+    }
+    
+    /// Check whether two points are equal, regardless of projective representation
+    #[wasm_bindgen]
+    pub fn equals(&self, other: &G2Point) -> bool {
+        unsafe { blst::blst_p2_is_equal(&self.point, &other.point) }
+    }
+    
+    /// Scalar multiplication by a 32-byte big-endian scalar
+    ///
+    /// Breaking change: earlier releases passed the decoded bytes to blst unreversed, so the
+    /// hex was effectively read as little-endian. Callers that relied on that order should
+    /// switch to `scalar_mul_le(scalar, 256)`.
+    #[wasm_bindgen]
+    pub fn scalar_mul(&self, scalar: &str) -> Result<G2Point, CryptoError> {
+        let bytes = hex::decode(scalar)
+            .map_err(|e| CryptoError::InvalidHex(e.to_string()))?;
+        self.scalar_mul_bytes(&bytes)
+    }
+    
+    /// Scalar multiplication by a big-endian scalar given as raw bytes, the binary form of `scalar_mul`
+    #[wasm_bindgen]
+    pub fn scalar_mul_bytes(&self, scalar: &[u8]) -> Result<G2Point, CryptoError> {
+        check_scalar_len(scalar)?;
+        let mut bytes = scalar.to_vec();
+        bytes.reverse();
+        
+        let mut result = blst_p2::default();
+        unsafe {
+            blst::blst_p2_mult(&mut result, &self.point, bytes.as_ptr(), 256);
+        }
+        
+        Ok(G2Point { point: result })
+    }
+    
+    /// Scalar multiplication by a little-endian scalar, using only its low `nbits` bits
+    #[wasm_bindgen]
+    pub fn scalar_mul_le(&self, scalar: &str, nbits: usize) -> Result<G2Point, CryptoError> {
+        let bytes = decode_scalar_hex(scalar, nbits)?;
+        let mut result = blst_p2::default();
+        unsafe {
+            blst::blst_p2_mult(&mut result, &self.point, bytes.as_ptr(), nbits);
+        }
+        Ok(G2Point { point: result })
+    }
+    
+    /// Scalar multiplication by a field-reduced Scalar
+    #[wasm_bindgen]
+    pub fn scalar_mul_fr(&self, scalar: &Scalar) -> G2Point {
+        let mut result = blst_p2::default();
+        unsafe {
+            blst::blst_p2_mult(&mut result, &self.point, scalar.value.b.as_ptr(), 255);
+        }
+        G2Point { point: result }
+    }
+    
+    /// Check if point is at infinity
+    #[wasm_bindgen]
+    pub fn is_infinity(&self) -> bool {
+        unsafe { blst::blst_p2_is_inf(&self.point) }
+    }
+    
+    /// Check that the point satisfies the curve equation
+    #[wasm_bindgen]
+    pub fn is_on_curve(&self) -> bool {
+        unsafe { blst::blst_p2_on_curve(&self.point) }
+    }
+    
+    /// Check that the point lies in the prime-order subgroup
+    #[wasm_bindgen]
+    pub fn is_in_subgroup(&self) -> bool {
+        unsafe { blst::blst_p2_in_g2(&self.point) }
+    }
+    
+    /// Compressed 96-byte encoding (ZCash format: x.c1 || x.c0, flag bits in the top byte)
+    #[wasm_bindgen]
+    pub fn compress(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; 96];
+        unsafe {
+            blst::blst_p2_compress(bytes.as_mut_ptr(), &self.point);
+        }
+        bytes
+    }
+    
+    /// Uncompressed 192-byte encoding (ZCash format: x.c1 || x.c0 || y.c1 || y.c0)
+    ///
+    /// Note the imaginary component comes first, unlike `get_coordinates` and EIP-2537.
+    #[wasm_bindgen]
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; 192];
+        unsafe {
+            blst::blst_p2_serialize(bytes.as_mut_ptr(), &self.point);
+        }
+        bytes
+    }
+    
+    /// Encode as EIP-2537 bytes: x.c0 || x.c1 || y.c0 || y.c1, each zero-padded to 64 bytes
+    #[wasm_bindgen]
+    pub fn to_eip2537_bytes(&self) -> Vec<u8> {
+        let mut point = blst_p2_affine::default();
+        unsafe {
+            blst::blst_p2_to_affine(&mut point, &self.point);
+        }
+        
+        let mut bytes = vec![0u8; 256];
+        if !self.is_infinity() {
+            fp_to_eip2537(&point.x.fp[0], &mut bytes[0..64]);
+            fp_to_eip2537(&point.x.fp[1], &mut bytes[64..128]);
+            fp_to_eip2537(&point.y.fp[0], &mut bytes[128..192]);
+            fp_to_eip2537(&point.y.fp[1], &mut bytes[192..256]);
+        }
+        bytes
+    }
+    
+    /// Decode EIP-2537 bytes, checking padding, curve and subgroup membership
+    #[wasm_bindgen]
+    pub fn from_eip2537_bytes(bytes: &[u8]) -> Result<G2Point, CryptoError> {
+        if bytes.len() != 256 {
+            return Err(CryptoError::WrongLength { expected: 256, got: bytes.len() });
+        }
+        
+        let affine = blst_p2_affine {
+            x: blst_fp2 {
+                fp: [fp_from_eip2537(&bytes[0..64])?, fp_from_eip2537(&bytes[64..128])?],
+            },
+            y: blst_fp2 {
+                fp: [fp_from_eip2537(&bytes[128..192])?, fp_from_eip2537(&bytes[192..256])?],
+            },
+        };
+        
+        G2Point::from_affine_checked(&affine)
+    }
+    
+    /// Decode the 96-byte compressed encoding, checking curve and subgroup membership
+    #[wasm_bindgen]
+    pub fn uncompress(bytes: &[u8]) -> Result<G2Point, CryptoError> {
+        if bytes.len() != 96 {
+            return Err(CryptoError::WrongLength { expected: 96, got: bytes.len() });
+        }
+        
+        let mut affine = blst_p2_affine::default();
+        let err = unsafe { blst::blst_p2_uncompress(&mut affine, bytes.as_ptr()) };
+        check_decode(err)?;
+        G2Point::from_affine_checked(&affine)
+    }
+    
+    /// Decode the 192-byte uncompressed encoding, checking curve and subgroup membership
+    #[wasm_bindgen]
+    pub fn deserialize(bytes: &[u8]) -> Result<G2Point, CryptoError> {
+        if bytes.len() != 192 {
+            return Err(CryptoError::WrongLength { expected: 192, got: bytes.len() });
+        }
+        
+        let mut affine = blst_p2_affine::default();
+        let err = unsafe { blst::blst_p2_deserialize(&mut affine, bytes.as_ptr()) };
+        check_decode(err)?;
+        G2Point::from_affine_checked(&affine)
+    }
+    
+    /// Get point coordinates as hex strings
+    #[wasm_bindgen]
+    pub fn get_coordinates(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.coordinates()).unwrap()
+    }
+    
+    /// Build a point from big-endian hex affine coordinates (each Fp2 as c0 + c1·u),
+    /// checking curve and subgroup membership
+    ///
+    /// As in `get_coordinates`, all-zero coordinates denote the point at infinity.
+    #[wasm_bindgen]
+    pub fn from_coordinates(x_c0: &str, x_c1: &str, y_c0: &str, y_c1: &str) -> Result<G2Point, CryptoError> {
+        let affine = blst_p2_affine {
+            x: blst_fp2 { fp: [fp_from_hex_canonical(x_c0)?, fp_from_hex_canonical(x_c1)?] },
+            y: blst_fp2 { fp: [fp_from_hex_canonical(y_c0)?, fp_from_hex_canonical(y_c1)?] },
+        };
+        G2Point::from_affine_checked(&affine)
+    }
+    
+    /// Like `from_coordinates` but without the subgroup check, for points that still need `clear_cofactor`
+    #[wasm_bindgen]
+    pub fn from_curve_coordinates(x_c0: &str, x_c1: &str, y_c0: &str, y_c1: &str) -> Result<G2Point, CryptoError> {
+        let affine = blst_p2_affine {
+            x: blst_fp2 { fp: [fp_from_hex_canonical(x_c0)?, fp_from_hex_canonical(x_c1)?] },
+            y: blst_fp2 { fp: [fp_from_hex_canonical(y_c0)?, fp_from_hex_canonical(y_c1)?] },
+        };
+        G2Point::from_affine_on_curve(&affine)
+    }
+    
+    /// Map any curve point into the prime-order subgroup, equivalent to multiplying by h_eff
+    ///
+    /// This is RFC 9380's `clear_cofactor` for G2 using the Budroni–Pintore method, which
+    /// replaces the 636-bit h_eff with two multiplications by z and two applications of ψ.
+    #[wasm_bindgen]
+    pub fn clear_cofactor(&self) -> G2Point {
+        let t1 = self.mul_by_z();
+        let psi_p = self.psi();
+        let t3 = self.double().psi().psi().subtract(&psi_p);
+        let t2 = t1.add(&psi_p).mul_by_z();
+        t3.add(&t2).subtract(&t1).subtract(self)
+    }
+}
+
+#[wasm_bindgen]
+impl Scalar {
+    /// Create a scalar from 32 big-endian bytes in hex, reducing values ≥ r modulo r
+    #[wasm_bindgen(constructor)]
+    pub fn new(hex_string: &str) -> Result<Scalar, CryptoError> {
+        let bytes = hex::decode(hex_string)
+            .map_err(|e| CryptoError::InvalidHex(e.to_string()))?;
+        
+        if bytes.len() != 32 {
+            return Err(CryptoError::WrongLength { expected: 32, got: bytes.len() });
+        }
+        
+        let mut value = blst_scalar::default();
+        unsafe {
+            blst::blst_scalar_from_be_bytes(&mut value, bytes.as_ptr(), bytes.len());
+        }
+        
+        Ok(Scalar { value })
+    }
+    
+    /// Create a scalar from a small integer
+    #[wasm_bindgen]
+    pub fn from_u64(v: u64) -> Scalar {
+        let mut value = blst_scalar::default();
+        unsafe {
+            blst::blst_scalar_from_uint64(&mut value, [v, 0, 0, 0].as_ptr());
+        }
+        Scalar { value }
+    }
+    
+    /// Parse 32 little-endian bytes (blst's native order), rejecting values ≥ r
+    #[wasm_bindgen]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Scalar, CryptoError> {
+        if bytes.len() != 32 {
+            return Err(CryptoError::WrongLength { expected: 32, got: bytes.len() });
+        }
+        
+        let mut value = blst_scalar::default();
+        value.b.copy_from_slice(bytes);
+        if !unsafe { blst::blst_scalar_fr_check(&value) } {
+            return Err(CryptoError::NonCanonical("scalar is not reduced modulo r"));
+        }
+        
+        Ok(Scalar { value })
+    }
+    
+    /// Serialize as 32 little-endian bytes
+    #[wasm_bindgen]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.value.b.to_vec()
+    }
+    
+    /// Draw a uniformly random nonzero scalar by rejection sampling
+    #[wasm_bindgen]
+    pub fn random() -> Result<Scalar, CryptoError> {
+        Scalar::random_with(fill_random)
+    }
+    
+    /// Deterministic nonzero scalar: the same seed always yields the same scalar
+    #[wasm_bindgen]
+    pub fn random_from_seed(seed: &[u8]) -> Scalar {
+        Scalar::random_with(seeded_fill(seed)).expect("seeded RNG cannot fail")
+    }
+    
+    /// Check if the scalar is zero
+    #[wasm_bindgen]
+    pub fn is_zero(&self) -> bool {
+        self.value.b.ct_eq(&[0u8; 32]).into()
+    }
+    
+    /// Constant-time equality
+    #[wasm_bindgen]
+    pub fn equals(&self, other: &Scalar) -> bool {
+        self.value.b.ct_eq(&other.value.b).into()
+    }
+    
+    /// Convert to a 32-byte big-endian hex string
+    #[wasm_bindgen]
+    pub fn to_hex(&self) -> String {
+        let mut bytes = [0u8; 32];
+        unsafe {
+            blst::blst_bendian_from_scalar(bytes.as_mut_ptr(), &self.value);
+        }
+        hex::encode(bytes)
+    }
+    
+    /// Add two scalars modulo r
+    #[wasm_bindgen]
+    pub fn add(&self, other: &Scalar) -> Scalar {
+        let mut result = blst_fr::default();
+        unsafe {
+            blst::blst_fr_add(&mut result, &self.to_fr(), &other.to_fr());
+        }
+        Scalar::from_fr(&result)
+    }
+    
+    /// Subtract another scalar modulo r
+    #[wasm_bindgen]
+    pub fn sub(&self, other: &Scalar) -> Scalar {
+        let mut result = blst_fr::default();
+        unsafe {
+            blst::blst_fr_sub(&mut result, &self.to_fr(), &other.to_fr());
+        }
+        Scalar::from_fr(&result)
+    }
+    
+    /// Multiply two scalars modulo r
+    #[wasm_bindgen]
+    pub fn mul(&self, other: &Scalar) -> Scalar {
+        let mut result = blst_fr::default();
+        unsafe {
+            blst::blst_fr_mul(&mut result, &self.to_fr(), &other.to_fr());
+        }
+        Scalar::from_fr(&result)
+    }
+    
+    /// Compute the multiplicative inverse modulo r
+    #[wasm_bindgen]
+    pub fn inverse(&self) -> Result<Scalar, CryptoError> {
+        if self.is_zero() {
+            return Err(CryptoError::ZeroInverse);
+        }
+        
+        let mut result = blst_fr::default();
+        unsafe {
+            blst::blst_fr_inverse(&mut result, &self.to_fr());
+        }
+        Ok(Scalar::from_fr(&result))
+    }
+    
+    /// Negate the scalar modulo r
+    #[wasm_bindgen]
+    pub fn neg(&self) -> Scalar {
+        let mut result = blst_fr::default();
+        unsafe {
+            blst::blst_fr_cneg(&mut result, &self.to_fr(), true);
+        }
+        Scalar::from_fr(&result)
+    }
+}
+
+impl Scalar {
+    fn random_with(mut fill: impl FnMut(&mut [u8]) -> Result<(), CryptoError>) -> Result<Scalar, CryptoError> {
+        let mut value = blst_scalar::default();
+        loop {
+            fill(&mut value.b)?;
+            // r is a 255-bit prime, so mask to 255 bits and retry anything ≥ r or zero
+            value.b[31] &= 0x7f;
+            if unsafe { blst::blst_sk_check(&value) } {
+                return Ok(Scalar { value });
+            }
+        }
+    }
+    
+    /// Convert to blst's Montgomery-form field element for arithmetic
+    pub(crate) fn to_fr(&self) -> blst_fr {
+        let mut fr = blst_fr::default();
+        unsafe {
+            blst::blst_fr_from_scalar(&mut fr, &self.value);
+        }
+        fr
+    }
+    
+    /// Convert back from Montgomery form
+    pub(crate) fn from_fr(fr: &blst_fr) -> Scalar {
+        let mut value = blst_scalar::default();
+        unsafe {
+            blst::blst_scalar_from_fr(&mut value, fr);
+        }
+        Scalar { value }
+    }
+    
+    /// Reduce an arbitrary-length big-endian byte string modulo r
+    ///
+    /// Feeding 64 uniform bytes gives a negligibly biased scalar, as needed for
+    /// Fiat–Shamir challenges.
+    pub(crate) fn from_wide_be(bytes: &[u8]) -> Scalar {
+        let mut value = blst_scalar::default();
+        unsafe {
+            blst::blst_scalar_from_be_bytes(&mut value, bytes.as_ptr(), bytes.len());
+        }
+        Scalar { value }
+    }
+}
+
+#[wasm_bindgen]
+impl GtElement {
+    /// The identity element of GT
+    #[wasm_bindgen]
+    pub fn one() -> GtElement {
+        GtElement { value: unsafe { *blst::blst_fp12_one() } }
+    }
+    
+    /// Parse a 576-byte big-endian hex encoding, rejecting values outside GT
+    #[wasm_bindgen]
+    pub fn from_hex(hex_string: &str) -> Result<GtElement, CryptoError> {
+        let bytes = hex::decode(hex_string)
+            .map_err(|e| CryptoError::InvalidHex(e.to_string()))?;
+        
+        if bytes.len() != 576 {
+            return Err(CryptoError::WrongLength { expected: 576, got: bytes.len() });
+        }
+        
+        // Same component order as blst_bendian_from_fp12
+        let mut value = blst_fp12::default();
+        let mut chunks = bytes.chunks(48);
+        for i in 0..3 {
+            for j in 0..2 {
+                for fp in value.fp6[j].fp2[i].fp.iter_mut() {
+                    unsafe {
+                        blst::blst_fp_from_bendian(fp, chunks.next().unwrap().as_ptr());
+                    }
+                }
+            }
+        }
+        
+        let element = GtElement { value };
+        if element.to_hex() != hex_string.to_lowercase() {
+            return Err(CryptoError::NonCanonical("GT element coordinates are not reduced modulo p"));
+        }
+        if !unsafe { blst::blst_fp12_in_group(&element.value) } {
+            return Err(CryptoError::NotInSubgroup);
+        }
+        
+        Ok(element)
+    }
+    
+    /// Convert to a 576-byte big-endian hex string
+    #[wasm_bindgen]
+    pub fn to_hex(&self) -> String {
+        let mut bytes = [0u8; 576];
+        unsafe {
+            blst::blst_bendian_from_fp12(bytes.as_mut_ptr(), &self.value);
+        }
+        hex::encode(bytes)
+    }
+    
+    /// Multiply two GT elements (the group operation)
+    #[wasm_bindgen]
+    pub fn mul(&self, other: &GtElement) -> GtElement {
+        let mut result = blst_fp12::default();
+        unsafe {
+            blst::blst_fp12_mul(&mut result, &self.value, &other.value);
+        }
+        GtElement { value: result }
+    }
+    
+    /// Compute the inverse in GT
+    #[wasm_bindgen]
+    pub fn inverse(&self) -> GtElement {
+        let mut result = blst_fp12::default();
+        unsafe {
+            blst::blst_fp12_inverse(&mut result, &self.value);
+        }
+        GtElement { value: result }
+    }
+    
+    /// Raise to a big-endian scalar power (square-and-multiply with cyclotomic squaring)
+    #[wasm_bindgen]
+    pub fn pow(&self, scalar_hex: &str) -> Result<GtElement, CryptoError> {
+        let exponent = decode_scalar_hex(scalar_hex, 256)?;
+        
+        let mut result = GtElement::one().value;
+        for byte in exponent {
+            for bit in (0..8).rev() {
+                unsafe {
+                    blst::blst_fp12_cyclotomic_sqr(&mut result, &result);
+                    if (byte >> bit) & 1 == 1 {
+                        blst::blst_fp12_mul(&mut result, &result, &self.value);
+                    }
+                }
+            }
+        }
+        
+        Ok(GtElement { value: result })
+    }
+    
+    /// Check whether two GT elements are equal
+    #[wasm_bindgen]
+    pub fn equals(&self, other: &GtElement) -> bool {
+        unsafe { blst::blst_fp12_is_equal(&self.value, &other.value) }
+    }
+}
+
+/// Evaluate at `x` the unique polynomial of degree < n through the points (xs[i], ys[i])
+///
+/// The points come as separate `xs` and `ys` because wasm-bindgen cannot pass a
+/// vector of tuples. Errors on mismatched lengths and empty input; a repeated
+/// x-coordinate makes a Lagrange denominator zero and yields `ZeroInverse`.
+#[wasm_bindgen]
+pub fn lagrange_interpolate(xs: Vec<Scalar>, ys: Vec<Scalar>, x: &Scalar) -> Result<Scalar, CryptoError> {
+    if xs.len() != ys.len() {
+        return Err(CryptoError::LengthMismatch { left: xs.len(), right: ys.len() });
+    }
+    if xs.is_empty() {
+        return Err(CryptoError::EmptyInput);
+    }
+    
+    let mut result = Scalar::from_u64(0);
+    for (i, (xi, yi)) in xs.iter().zip(&ys).enumerate() {
+        let mut numerator = Scalar::from_u64(1);
+        let mut denominator = Scalar::from_u64(1);
+        for (j, xj) in xs.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            numerator = numerator.mul(&x.sub(xj));
+            denominator = denominator.mul(&xi.sub(xj));
+        }
+        result = result.add(&yi.mul(&numerator).mul(&denominator.inverse()?));
+    }
+    Ok(result)
+}
+
+/// Pairing operation between G1 and G2 points
+#[wasm_bindgen]
+pub fn pairing(g1: &G1Point, g2: &G2Point) -> GtElement {
+    let mut result = miller_loop(g1, g2);
+    unsafe {
+        blst::blst_final_exp(&mut result, &result);
+    }
+    
+    GtElement { value: result }
+}
+
+/// Product of pairings e(g1s[i], g2s[i]), sharing a single final exponentiation
+#[wasm_bindgen]
+pub fn multi_pairing(g1s: Vec<G1Point>, g2s: Vec<G2Point>) -> Result<GtElement, CryptoError> {
+    if g1s.len() != g2s.len() {
+        return Err(CryptoError::LengthMismatch { left: g1s.len(), right: g2s.len() });
+    }
+    
+    let mut result = miller_product(&g1s, &g2s);
+    unsafe {
+        blst::blst_final_exp(&mut result, &result);
+    }
+    
+    Ok(GtElement { value: result })
+}
+
+/// Check that the product of pairings e(g1s[i], g2s[i]) is the identity in GT
+///
+/// Returns false when the input lengths differ.
+#[wasm_bindgen]
+pub fn pairing_check(g1s: Vec<G1Point>, g2s: Vec<G2Point>) -> bool {
+    if g1s.len() != g2s.len() {
+        return false;
+    }
+    
+    let mut result = miller_product(&g1s, &g2s);
+    unsafe {
+        blst::blst_final_exp(&mut result, &result);
+        blst::blst_fp12_is_one(&result)
+    }
+}
+
+/// Check e(p, q) == e(r, s) as e(p, q)·e(-r, s) == 1, with one two-term Miller loop and one final exponentiation
+#[wasm_bindgen]
+pub fn pairing_equal(p: &G1Point, q: &G2Point, r: &G1Point, s: &G2Point) -> bool {
+    // blst's line evaluation has no special case for infinity, and such a pair contributes 1 anyway
+    let pairs = [(*p, *q), (r.negate(), *s)];
+    let (g1s, g2s): (Vec<G1Point>, Vec<G2Point>) = pairs
+        .into_iter()
+        .filter(|(g1, g2)| !g1.is_infinity() && !g2.is_infinity())
+        .unzip();
+    if g1s.is_empty() {
+        return true;
+    }
+    
+    let g1_affines = g1_to_affines(&g1s);
+    let g2_affines: Vec<blst_p2_affine> = g2s
+        .iter()
+        .map(|g2| {
+            let mut affine = blst_p2_affine::default();
+            unsafe {
+                blst::blst_p2_to_affine(&mut affine, &g2.point);
+            }
+            affine
+        })
+        .collect();
+    
+    // A null second entry tells blst the first pointer addresses a contiguous array
+    let g1_ptrs: [*const blst_p1_affine; 2] = [g1_affines.as_ptr(), std::ptr::null()];
+    let g2_ptrs: [*const blst_p2_affine; 2] = [g2_affines.as_ptr(), std::ptr::null()];
+    let mut result = blst_fp12::default();
+    unsafe {
+        blst::blst_miller_loop_n(&mut result, g2_ptrs.as_ptr(), g1_ptrs.as_ptr(), g1_affines.len());
+        blst::blst_final_exp(&mut result, &result);
+        blst::blst_fp12_is_one(&result)
+    }
+}
+
+/// Product of the Miller loops of each pair, before final exponentiation
+fn miller_product(g1s: &[G1Point], g2s: &[G2Point]) -> blst_fp12 {
+    let mut result = GtElement::one().value;
+    for (g1, g2) in g1s.iter().zip(g2s) {
+        let term = miller_loop(g1, g2);
+        unsafe {
+            blst::blst_fp12_mul(&mut result, &result, &term);
+        }
+    }
+    result
+}
+
+/// Miller loop of a single pair, before final exponentiation
+fn miller_loop(g1: &G1Point, g2: &G2Point) -> blst_fp12 {
+    let mut result = blst_fp12::default();
+    
+    // Convert to affine points for pairing
+    let mut g1_affine = blst_p1_affine::default();
+    let mut g2_affine = blst_p2_affine::default();
+    
+    unsafe {
+        blst::blst_p1_to_affine(&mut g1_affine, &g1.point);
+        blst::blst_p2_to_affine(&mut g2_affine, &g2.point);
+        blst::blst_miller_loop(&mut result, &g2_affine, &g1_affine);
+    }
+    
+    result
+}
+
+/// Multi-scalar multiplication sum(scalars[i] * points[i]) using Pippenger's algorithm
+#[wasm_bindgen]
+pub fn g1_msm(points: Vec<G1Point>, scalars: Vec<Scalar>) -> Result<G1Point, CryptoError> {
+    if points.len() != scalars.len() {
+        return Err(CryptoError::LengthMismatch { left: points.len(), right: scalars.len() });
+    }
+    if points.is_empty() {
+        return Ok(G1Point::identity());
+    }
+    
+    let affines = g1_to_affines(&points);
+    let scalar_bytes: Vec<u8> = scalars.iter().flat_map(|s| s.value.b).collect();
+    
+    Ok(G1Point { point: g1_pippenger(&affines, &scalar_bytes) })
+}
+
+/// Multi-scalar multiplication sum(scalars[i] * points[i]) in G2, as `g1_msm` does for G1
+#[wasm_bindgen]
+pub fn g2_msm(points: Vec<G2Point>, scalars: Vec<Scalar>) -> Result<G2Point, CryptoError> {
+    if points.len() != scalars.len() {
+        return Err(CryptoError::LengthMismatch { left: points.len(), right: scalars.len() });
+    }
+    if points.is_empty() {
+        return Ok(G2Point::identity());
+    }
+    
+    let affines = g2_to_affines(&points);
+    let scalar_bytes: Vec<u8> = scalars.iter().flat_map(|s| s.value.b).collect();
+    
+    Ok(G2Point { point: g2_pippenger(&affines, &scalar_bytes) })
+}
+
+/// Inner product <scalars, points> = sum(scalars[i] * points[i]), computed by `g1_msm`
+///
+/// A length mismatch is reported by `g1_msm`, so `left` is the number of points.
+#[wasm_bindgen]
+pub fn inner_product(scalars: Vec<Scalar>, points: Vec<G1Point>) -> Result<G1Point, CryptoError> {
+    g1_msm(points, scalars)
+}
+
+/// Sum of all points in one call, or the point at infinity for no points
+#[wasm_bindgen]
+pub fn g1_sum(points: Vec<G1Point>) -> G1Point {
+    let mut sum = blst_p1::default();
+    for p in &points {
+        unsafe {
+            blst::blst_p1_add_or_double(&mut sum, &sum, &p.point);
+        }
+    }
+    G1Point { point: sum }
+}
+
+/// Sum of all field elements in one call, or zero for no elements
+#[wasm_bindgen]
+pub fn fp_sum(elements: Vec<FpElement>) -> FpElement {
+    let mut sum = blst_fp::default();
+    for e in &elements {
+        unsafe {
+            blst::blst_fp_add(&mut sum, &sum, &e.value);
+        }
+    }
+    FpElement { value: sum }
+}
+
+/// Sum of all scalars modulo r, or zero for no scalars
+///
+/// Accumulates in Montgomery form, so each scalar is converted once rather than per addition.
+#[wasm_bindgen]
+pub fn scalar_sum(scalars: Vec<Scalar>) -> Scalar {
+    let mut sum = blst_fr::default();
+    for s in &scalars {
+        unsafe {
+            blst::blst_fr_add(&mut sum, &sum, &s.to_fr());
+        }
+    }
+    Scalar::from_fr(&sum)
+}
+
+/// Smallest window accepted by `g1_msm_windowed`
+pub const MSM_MIN_WINDOW_BITS: usize = 2;
+
+/// Largest window accepted by `g1_msm_windowed`; its 2^15 buckets take 6 MB of scratch
+pub const MSM_MAX_WINDOW_BITS: usize = 16;
+
+/// `g1_msm` with an explicit Pippenger window size instead of blst's choice for the input size
+///
+/// blst picks 8 bits for 1024 points. This always runs on one thread, even with the
+/// `parallel` feature, because the best window depends on how many points each thread sees.
+#[wasm_bindgen]
+pub fn g1_msm_windowed(points: Vec<G1Point>, scalars: Vec<Scalar>, window_bits: usize) -> Result<G1Point, CryptoError> {
+    if points.len() != scalars.len() {
+        return Err(CryptoError::LengthMismatch { left: points.len(), right: scalars.len() });
+    }
+    if !(MSM_MIN_WINDOW_BITS..=MSM_MAX_WINDOW_BITS).contains(&window_bits) {
+        return Err(CryptoError::InvalidParameter(format!(
+            "window_bits must be between {} and {}, got {}",
+            MSM_MIN_WINDOW_BITS, MSM_MAX_WINDOW_BITS, window_bits
+        )));
+    }
+    match (points.as_slice(), scalars.as_slice()) {
+        ([], []) => return Ok(G1Point::identity()),
+        // blst's tiles assume at least two points
+        ([point], [scalar]) => return Ok(point.scalar_mul_fr(scalar)),
+        _ => {}
+    }
+    
+    let affines = g1_to_affines(&points);
+    let scalar_bytes: Vec<u8> = scalars.iter().flat_map(|s| s.value.b).collect();
+    
+    Ok(G1Point { point: g1_pippenger_windowed(&affines, &scalar_bytes, window_bits) })
+}
+
+/// Single-threaded Pippenger with a fixed window, running blst's tiles from the top bits down
+fn g1_pippenger_windowed(affines: &[blst_p1_affine], scalars: &[u8], window: usize) -> blst_p1 {
+    const NBITS: usize = 255;
+    let point_ptrs: [*const blst_p1_affine; 2] = [affines.as_ptr(), std::ptr::null()];
+    let scalar_ptrs: [*const u8; 2] = [scalars.as_ptr(), std::ptr::null()];
+    
+    // One XYZZ bucket (four field elements) per nonzero signed digit magnitude
+    let bucket_limbs = 4 * std::mem::size_of::<blst_fp>() / 8;
+    let mut buckets = vec![0u64; bucket_limbs << (window - 1)];
+    
+    // The top tile takes the NBITS % window excess bits (possibly none, leaving only the carry)
+    let mut bit0 = NBITS - NBITS % window;
+    let mut result = blst_p1::default();
+    loop {
+        let mut tile = blst_p1::default();
+        unsafe {
+            blst::blst_p1s_tile_pippenger(
+                &mut tile,
+                point_ptrs.as_ptr(),
+                affines.len(),
+                scalar_ptrs.as_ptr(),
+                NBITS,
+                buckets.as_mut_ptr(),
+                bit0,
+                window,
+            );
+            blst::blst_p1_add_or_double(&mut result, &result, &tile);
+        }
+        if bit0 == 0 {
+            return result;
+        }
+        
+        bit0 -= window;
+        for _ in 0..window {
+            unsafe {
+                blst::blst_p1_double(&mut result, &result);
+            }
+        }
+    }
+}
+
+/// Bits per window in a `G1Precomputed` table
+const PRECOMP_WINDOW_BITS: usize = 4;
+
+/// Signed digits lie in [-8, 8], so each window stores the multiples 1..=8
+const PRECOMP_HALF: usize = 1 << (PRECOMP_WINDOW_BITS - 1);
+
+/// 64 windows cover 256 bits, plus one for the carry out of the top digit
+const PRECOMP_WINDOWS: usize = 256 / PRECOMP_WINDOW_BITS + 1;
+
+/// A G1 point with precomputed multiples, for multiplying one fixed point by many scalars
+///
+/// Window i holds k·16^i·P for k in 1..=8, so a multiplication is one mixed addition per
+/// signed 4-bit digit and no doublings. Every table entry is scanned on each lookup, so
+/// memory access does not depend on the scalar.
+#[wasm_bindgen]
+pub struct G1Precomputed {
+    table: Vec<blst_p1_affine>,
+}
+
+#[wasm_bindgen]
+impl G1Precomputed {
+    /// Build the table (65 windows of 8 affine points, about 50 KB)
+    #[wasm_bindgen(constructor)]
+    pub fn new(point: &G1Point) -> G1Precomputed {
+        let mut multiples = Vec::with_capacity(PRECOMP_WINDOWS * PRECOMP_HALF);
+        let mut base = point.point;
+        
+        for _ in 0..PRECOMP_WINDOWS {
+            let mut acc = base;
+            multiples.push(G1Point { point: acc });
+            for _ in 1..PRECOMP_HALF {
+                unsafe {
+                    blst::blst_p1_add_or_double(&mut acc, &acc, &base);
+                }
+                multiples.push(G1Point { point: acc });
+            }
+            for _ in 0..PRECOMP_WINDOW_BITS {
+                unsafe {
+                    blst::blst_p1_double(&mut base, &base);
+                }
+            }
+        }
+        
+        G1Precomputed { table: g1_to_affines(&multiples) }
+    }
+    
+    /// Multiply the precomputed point by a scalar
+    #[wasm_bindgen]
+    pub fn scalar_mul(&self, scalar: &Scalar) -> G1Point {
+        let mut acc = blst_p1::default();
+        for (window, digit) in self.table.chunks_exact(PRECOMP_HALF).zip(signed_digits(scalar)) {
+            let mut entry = ct_select_multiple(window, digit.unsigned_abs() as usize);
+            unsafe {
+                blst::blst_fp_cneg(&mut entry.y, &entry.y, digit < 0);
+                blst::blst_p1_add_or_double_affine(&mut acc, &acc, &entry);
+            }
+        }
+        G1Point { point: acc }
+    }
+}
+
+/// Recode a scalar into base-16 digits in [-8, 8], least significant first
+fn signed_digits(scalar: &Scalar) -> [i8; PRECOMP_WINDOWS] {
+    let mut digits = [0i8; PRECOMP_WINDOWS];
+    let mut carry = 0i32;
+    for (i, digit) in digits.iter_mut().enumerate() {
+        let byte = scalar.value.b.get(i / 2).copied().unwrap_or(0);
+        let nibble = ((byte >> (4 * (i % 2))) & 0xf) as i32 + carry;
+        // nibble is at most 16, so this is 1 exactly when it exceeds 8
+        carry = (nibble + 7) >> 4;
+        *digit = (nibble - (carry << 4)) as i8;
+    }
+    digits
+}
+
+/// Pick `window[k - 1]`, or the point at infinity for k = 0, touching every entry
+fn ct_select_multiple(window: &[blst_p1_affine], k: usize) -> blst_p1_affine {
+    let mut out = blst_p1_affine::default();
+    for (i, entry) in window.iter().enumerate() {
+        let mask = 0u64.wrapping_sub(u64::from(i + 1 == k));
+        for (o, e) in out.x.l.iter_mut().zip(&entry.x.l) {
+            *o |= e & mask;
+        }
+        for (o, e) in out.y.l.iter_mut().zip(&entry.y.l) {
+            *o |= e & mask;
+        }
+    }
+    out
+}
+
+/// The G2 counterpart of `G1Precomputed` (about 100 KB), used for the generator table
+struct G2Precomputed {
+    table: Vec<blst_p2_affine>,
+}
+
+impl G2Precomputed {
+    fn new(point: &G2Point) -> G2Precomputed {
+        let mut multiples = Vec::with_capacity(PRECOMP_WINDOWS * PRECOMP_HALF);
+        let mut base = point.point;
+        
+        for _ in 0..PRECOMP_WINDOWS {
+            let mut acc = base;
+            multiples.push(acc);
+            for _ in 1..PRECOMP_HALF {
+                unsafe {
+                    blst::blst_p2_add_or_double(&mut acc, &acc, &base);
+                }
+                multiples.push(acc);
+            }
+            for _ in 0..PRECOMP_WINDOW_BITS {
+                unsafe {
+                    blst::blst_p2_double(&mut base, &base);
+                }
+            }
+        }
+        
+        let mut table = vec![blst_p2_affine::default(); multiples.len()];
+        let ptrs: [*const blst_p2; 2] = [multiples.as_ptr(), std::ptr::null()];
+        unsafe {
+            blst::blst_p2s_to_affine(table.as_mut_ptr(), ptrs.as_ptr(), multiples.len());
+        }
+        G2Precomputed { table }
+    }
+    
+    fn scalar_mul(&self, scalar: &Scalar) -> G2Point {
+        let mut acc = blst_p2::default();
+        for (window, digit) in self.table.chunks_exact(PRECOMP_HALF).zip(signed_digits(scalar)) {
+            let mut entry = ct_select_multiple_g2(window, digit.unsigned_abs() as usize);
+            unsafe {
+                blst::blst_fp2_cneg(&mut entry.y, &entry.y, digit < 0);
+                blst::blst_p2_add_or_double_affine(&mut acc, &acc, &entry);
+            }
+        }
+        G2Point { point: acc }
+    }
+}
+
+/// `ct_select_multiple` for G2 tables
+fn ct_select_multiple_g2(window: &[blst_p2_affine], k: usize) -> blst_p2_affine {
+    let mut out = blst_p2_affine::default();
+    for (i, entry) in window.iter().enumerate() {
+        let mask = 0u64.wrapping_sub(u64::from(i + 1 == k));
+        for (out_fp, entry_fp) in out.x.fp.iter_mut().chain(out.y.fp.iter_mut()).zip(entry.x.fp.iter().chain(&entry.y.fp)) {
+            for (o, e) in out_fp.l.iter_mut().zip(&entry_fp.l) {
+                *o |= e & mask;
+            }
+        }
+    }
+    out
+}
+
+/// Multiply the G1 generator by a scalar, using a `G1Precomputed` table built on first use
+///
+/// About twice as fast as `G1Point::generator().scalar_mul_fr` once the table exists.
+#[wasm_bindgen]
+pub fn g1_mul_generator(scalar: &Scalar) -> G1Point {
+    static TABLE: std::sync::OnceLock<G1Precomputed> = std::sync::OnceLock::new();
+    TABLE.get_or_init(|| G1Precomputed::new(&G1Point::generator())).scalar_mul(scalar)
+}
+
+/// Multiply the G2 generator by a scalar, using a precomputed table built on first use
+///
+/// About 1.5 times as fast as `G2Point::generator().scalar_mul_fr` once the table exists.
+#[wasm_bindgen]
+pub fn g2_mul_generator(scalar: &Scalar) -> G2Point {
+    static TABLE: std::sync::OnceLock<G2Precomputed> = std::sync::OnceLock::new();
+    TABLE.get_or_init(|| G2Precomputed::new(&G2Point::generator())).scalar_mul(scalar)
+}
+
+/// Smallest chunk worth handing to its own thread in a parallel MSM
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+const PARALLEL_MSM_MIN_CHUNK: usize = 256;
+
+/// Pippenger MSM split across rayon threads, summing the partial results
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+fn g1_pippenger(affines: &[blst_p1_affine], scalars: &[u8]) -> blst_p1 {
+    use rayon::prelude::*;
+    
+    let chunk = affines
+        .len()
+        .div_ceil(rayon::current_num_threads())
+        .max(PARALLEL_MSM_MIN_CHUNK);
+    
+    affines
+        .par_chunks(chunk)
+        .zip(scalars.par_chunks(chunk * 32))
+        .map(|(points, scalars)| g1_pippenger_serial(points, scalars))
+        .reduce(blst_p1::default, |a, b| {
+            let mut sum = blst_p1::default();
+            unsafe {
+                blst::blst_p1_add_or_double(&mut sum, &a, &b);
+            }
+            sum
+        })
+}
+
+#[cfg(not(all(feature = "parallel", not(target_arch = "wasm32"))))]
+fn g1_pippenger(affines: &[blst_p1_affine], scalars: &[u8]) -> blst_p1 {
+    g1_pippenger_serial(affines, scalars)
+}
+
+/// Single-threaded Pippenger over affine points and 32-byte little-endian scalars
+fn g1_pippenger_serial(affines: &[blst_p1_affine], scalars: &[u8]) -> blst_p1 {
+    g1_pippenger_serial_bits(affines, scalars, 255)
+}
+
+/// Single-threaded multi-scalar multiplication with `nbits`-bit little-endian scalars packed back to back
+///
+/// Below 32 points blst switches from Pippenger to interleaved fixed windows (Straus).
+fn g1_pippenger_serial_bits(affines: &[blst_p1_affine], scalars: &[u8], nbits: usize) -> blst_p1 {
+    // A null second entry tells blst the first pointer addresses a contiguous array
+    let point_ptrs: [*const blst_p1_affine; 2] = [affines.as_ptr(), std::ptr::null()];
+    let scalar_ptrs: [*const u8; 2] = [scalars.as_ptr(), std::ptr::null()];
+    
+    let mut result = blst_p1::default();
+    unsafe {
+        let mut scratch = vec![0u64; blst::blst_p1s_mult_pippenger_scratch_sizeof(affines.len()) / 8];
+        blst::blst_p1s_mult_pippenger(
+            &mut result,
+            point_ptrs.as_ptr(),
+            affines.len(),
+            scalar_ptrs.as_ptr(),
+            nbits,
+            scratch.as_mut_ptr(),
+        );
+    }
+    result
+}
+
+/// G2 counterpart of `g1_pippenger`, split across rayon threads the same way
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+fn g2_pippenger(affines: &[blst_p2_affine], scalars: &[u8]) -> blst_p2 {
+    use rayon::prelude::*;
+    
+    let chunk = affines
+        .len()
+        .div_ceil(rayon::current_num_threads())
+        .max(PARALLEL_MSM_MIN_CHUNK);
+    
+    affines
+        .par_chunks(chunk)
+        .zip(scalars.par_chunks(chunk * 32))
+        .map(|(points, scalars)| g2_pippenger_serial(points, scalars))
+        .reduce(blst_p2::default, |a, b| {
+            let mut sum = blst_p2::default();
+            unsafe {
+                blst::blst_p2_add_or_double(&mut sum, &a, &b);
+            }
+            sum
+        })
+}
+
+#[cfg(not(all(feature = "parallel", not(target_arch = "wasm32"))))]
+fn g2_pippenger(affines: &[blst_p2_affine], scalars: &[u8]) -> blst_p2 {
+    g2_pippenger_serial(affines, scalars)
+}
+
+/// Single-threaded Pippenger over affine G2 points and 32-byte little-endian scalars
+fn g2_pippenger_serial(affines: &[blst_p2_affine], scalars: &[u8]) -> blst_p2 {
+    let point_ptrs: [*const blst_p2_affine; 2] = [affines.as_ptr(), std::ptr::null()];
+    let scalar_ptrs: [*const u8; 2] = [scalars.as_ptr(), std::ptr::null()];
+    
+    let mut result = blst_p2::default();
+    unsafe {
+        let mut scratch = vec![0u64; blst::blst_p2s_mult_pippenger_scratch_sizeof(affines.len()) / 8];
+        blst::blst_p2s_mult_pippenger(
+            &mut result,
+            point_ptrs.as_ptr(),
+            affines.len(),
+            scalar_ptrs.as_ptr(),
+            255,
+            scratch.as_mut_ptr(),
+        );
+    }
+    result
+}
+
+/// Effective G1 cofactor from RFC 9380 section 8.8.1, h_eff = 1 - z
+const G1_H_EFF: u64 = 0xd201000000010001;
+
+/// λ = z² − 1 as big-endian bytes; it satisfies λ² + λ + 1 = r
+const GLV_LAMBDA: [u8; 16] = [
+    0xac, 0x45, 0xa4, 0x01, 0x00, 0x01, 0xa4, 0x02,
+    0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff,
+];
+
+/// Cube root of unity β in Fp for which (βx, y) = λ·(x, y) on G1, big-endian
+const GLV_BETA: [u8; 48] = [
+    0x1a, 0x01, 0x11, 0xea, 0x39, 0x7f, 0xe6, 0x99, 0xec, 0x02, 0x40, 0x86,
+    0x63, 0xd4, 0xde, 0x85, 0xaa, 0x0d, 0x85, 0x7d, 0x89, 0x75, 0x9a, 0xd4,
+    0x89, 0x7d, 0x29, 0x65, 0x0f, 0xb8, 0x5f, 0x9b, 0x40, 0x94, 0x27, 0xeb,
+    0x4f, 0x49, 0xff, 0xfd, 0x8b, 0xfd, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xac,
+];
+
+/// Both GLV halves are at most λ + 1, which fits in 129 bits
+const GLV_HALF_BITS: usize = 129;
+const GLV_HALF_BYTES: usize = GLV_HALF_BITS.div_ceil(8);
+
+/// |z| for the BLS parameter z = -0xd201000000010000
+const BLS_Z_ABS: u64 = 0xd201000000010000;
+
+/// Imaginary part of 1 / (1 + i)^((p - 1) / 3), the x coefficient of ψ (its real part is 0), big-endian
+const PSI_COEFF_X_C1: [u8; 48] = [
+    0x1a, 0x01, 0x11, 0xea, 0x39, 0x7f, 0xe6, 0x99, 0xec, 0x02, 0x40, 0x86,
+    0x63, 0xd4, 0xde, 0x85, 0xaa, 0x0d, 0x85, 0x7d, 0x89, 0x75, 0x9a, 0xd4,
+    0x89, 0x7d, 0x29, 0x65, 0x0f, 0xb8, 0x5f, 0x9b, 0x40, 0x94, 0x27, 0xeb,
+    0x4f, 0x49, 0xff, 0xfd, 0x8b, 0xfd, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xad,
+];
+
+/// 1 / (1 + i)^((p - 1) / 2), the y coefficient of ψ, as big-endian (c0, c1)
+const PSI_COEFF_Y: [[u8; 48]; 2] = [
+    [
+        0x13, 0x52, 0x03, 0xe6, 0x01, 0x80, 0xa6, 0x8e, 0xe2, 0xe9, 0xc4, 0x48,
+        0xd7, 0x7a, 0x2c, 0xd9, 0x1c, 0x3d, 0xed, 0xd9, 0x30, 0xb1, 0xcf, 0x60,
+        0xef, 0x39, 0x64, 0x89, 0xf6, 0x1e, 0xb4, 0x5e, 0x30, 0x44, 0x66, 0xcf,
+        0x3e, 0x67, 0xfa, 0x0a, 0xf1, 0xee, 0x7b, 0x04, 0x12, 0x1b, 0xde, 0xa2,
+    ],
+    [
+        0x06, 0xaf, 0x0e, 0x04, 0x37, 0xff, 0x40, 0x0b, 0x68, 0x31, 0xe3, 0x6d,
+        0x6b, 0xd1, 0x7f, 0xfe, 0x48, 0x39, 0x5d, 0xab, 0xc2, 0xd3, 0x43, 0x5e,
+        0x77, 0xf7, 0x6e, 0x17, 0x00, 0x92, 0x41, 0xc5, 0xee, 0x67, 0x99, 0x2f,
+        0x72, 0xec, 0x05, 0xf4, 0xc8, 0x10, 0x84, 0xfb, 0xed, 0xe3, 0xcc, 0x09,
+    ],
+];
+
+/// Split k into little-endian (k1, k2) with k = k1 + k2·λ
+fn glv_split(k: &Scalar) -> ([u8; GLV_HALF_BYTES], [u8; GLV_HALF_BYTES]) {
+    let lambda = num_bigint::BigUint::from_bytes_be(&GLV_LAMBDA);
+    let k = num_bigint::BigUint::from_bytes_le(&k.value.b);
+    let (k1, k2) = (&k % &lambda, &k / &lambda);
+    
+    let to_half = |n: num_bigint::BigUint| {
+        let mut out = [0u8; GLV_HALF_BYTES];
+        let bytes = n.to_bytes_le();
+        out[..bytes.len()].copy_from_slice(&bytes);
+        out
+    };
+    (to_half(k1), to_half(k2))
+}
+
+/// Convert many G1 points to affine coordinate objects (same shape as `get_coordinates`)
+///
+/// Uses Montgomery's trick, so the whole batch costs one field inversion instead of one per point.
+#[wasm_bindgen]
+pub fn g1_batch_to_affine(points: Vec<G1Point>) -> Vec<JsValue> {
+    g1_batch_coordinates(&points)
+        .iter()
+        .map(|c| serde_wasm_bindgen::to_value(c).unwrap())
+        .collect()
+}
+
+/// A batch of compressed points written back to back into WASM linear memory
+///
+/// Layout: `count` points of `point_size` bytes each (48 per G1, 96 per G2) in their ZCash
+/// compressed encoding, starting at `offset`. JS can read the whole batch without copying:
+///
+/// ```js
+/// const view = new Uint8Array(wasm_memory().buffer, buf.offset(), buf.len());
+/// ```
+///
+/// The view is only valid while the buffer is alive and until the memory next grows,
+/// which detaches `memory.buffer`; call `free()` when done.
+#[wasm_bindgen]
+pub struct PointBuffer {
+    bytes: Vec<u8>,
+    point_size: usize,
+}
+
+#[wasm_bindgen]
+impl PointBuffer {
+    /// Compress G1 points into a new buffer (48 bytes each)
+    #[wasm_bindgen]
+    pub fn from_g1(points: Vec<G1Point>) -> PointBuffer {
+        let mut bytes = vec![0u8; points.len() * 48];
+        for (p, out) in points.iter().zip(bytes.chunks_exact_mut(48)) {
+            unsafe {
+                blst::blst_p1_compress(out.as_mut_ptr(), &p.point);
+            }
+        }
+        PointBuffer { bytes, point_size: 48 }
+    }
+    
+    /// Compress G2 points into a new buffer (96 bytes each)
+    #[wasm_bindgen]
+    pub fn from_g2(points: Vec<G2Point>) -> PointBuffer {
+        let mut bytes = vec![0u8; points.len() * 96];
+        for (p, out) in points.iter().zip(bytes.chunks_exact_mut(96)) {
+            unsafe {
+                blst::blst_p2_compress(out.as_mut_ptr(), &p.point);
+            }
+        }
+        PointBuffer { bytes, point_size: 96 }
+    }
+    
+    /// Byte offset of the first point in WASM memory
+    #[wasm_bindgen]
+    pub fn offset(&self) -> usize {
+        self.bytes.as_ptr() as usize
+    }
+    
+    /// Total length in bytes
+    #[wasm_bindgen]
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+    
+    /// Whether the buffer holds no points
+    #[wasm_bindgen]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+    
+    /// Bytes per point: 48 for G1, 96 for G2
+    #[wasm_bindgen]
+    pub fn point_size(&self) -> usize {
+        self.point_size
+    }
+    
+    /// Number of points in the buffer
+    #[wasm_bindgen]
+    pub fn count(&self) -> usize {
+        self.bytes.len() / self.point_size
+    }
+}
+
+impl PointBuffer {
+    /// Rust-side counterpart of the JS view over `offset()..offset() + len()`
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// The module's `WebAssembly.Memory`, for building views over a `PointBuffer`
+#[wasm_bindgen]
+pub fn wasm_memory() -> JsValue {
+    wasm_bindgen::memory()
+}
+
+/// Rust-side counterpart of `g1_batch_to_affine`
+pub fn g1_batch_coordinates(points: &[G1Point]) -> Vec<serde_json::Value> {
+    g1_to_affines(points)
+        .iter()
+        .zip(points)
+        .map(|(affine, p)| g1_affine_json(affine, p.is_infinity()))
+        .collect()
+}
+
+fn g1_affine_json(affine: &blst_p1_affine, infinity: bool) -> serde_json::Value {
+    serde_json::json!({
+        "x": fp_to_hex(&affine.x),
+        "y": fp_to_hex(&affine.y),
+        "infinity": infinity
+    })
+}
+
+/// Convert projective points to affine with a single shared inversion
+fn g1_to_affines(points: &[G1Point]) -> Vec<blst_p1_affine> {
+    let mut affines = vec![blst_p1_affine::default(); points.len()];
+    let raw: Vec<blst_p1> = points.iter().map(|p| p.point).collect();
+    let ptrs: [*const blst_p1; 2] = [raw.as_ptr(), std::ptr::null()];
+    unsafe {
+        blst::blst_p1s_to_affine(affines.as_mut_ptr(), ptrs.as_ptr(), raw.len());
+    }
+    affines
+}
+
+fn g2_to_affines(points: &[G2Point]) -> Vec<blst_p2_affine> {
+    let mut affines = vec![blst_p2_affine::default(); points.len()];
+    let raw: Vec<blst_p2> = points.iter().map(|p| p.point).collect();
+    let ptrs: [*const blst_p2; 2] = [raw.as_ptr(), std::ptr::null()];
+    unsafe {
+        blst::blst_p2s_to_affine(affines.as_mut_ptr(), ptrs.as_ptr(), raw.len());
+    }
+    affines
+}
+
+/// Hash a message to a G1 point (RFC 9380 hash_to_curve, BLS12381G1_XMD:SHA-256_SSWU_RO_)
+///
+/// The result is uniformly distributed in the prime-order subgroup and deterministic per (msg, dst).
+#[wasm_bindgen]
+pub fn hash_to_g1(msg: &[u8], dst: &[u8]) -> G1Point {
+    let mut point = blst_p1::default();
+    unsafe {
+        blst::blst_hash_to_g1(
+            &mut point,
+            msg.as_ptr(),
+            msg.len(),
+            dst.as_ptr(),
+            dst.len(),
+            std::ptr::null(),
+            0,
+        );
+    }
+    G1Point { point }
+}
+
+/// Encode a message to a G1 point (RFC 9380 encode_to_curve, BLS12381G1_XMD:SHA-256_SSWU_NU_)
+///
+/// Cheaper than `hash_to_g1` (one map-to-curve instead of two), but the output is not
+/// uniformly distributed. It is still deterministic and lies in the prime-order subgroup.
+#[wasm_bindgen]
+pub fn encode_to_g1(msg: &[u8], dst: &[u8]) -> G1Point {
+    let mut point = blst_p1::default();
+    unsafe {
+        blst::blst_encode_to_g1(
+            &mut point,
+            msg.as_ptr(),
+            msg.len(),
+            dst.as_ptr(),
+            dst.len(),
+            std::ptr::null(),
+            0,
+        );
+    }
+    G1Point { point }
+}
+
+/// Map a field element to a G1 point (RFC 9380 map_to_curve with the simplified SWU map, then clear_cofactor)
+///
+/// This is the step `encode_to_g1` applies after hash_to_field, for protocols that derive u themselves.
+#[wasm_bindgen]
+pub fn map_to_g1(u: &FpElement) -> G1Point {
+    let mut point = blst_p1::default();
+    unsafe {
+        blst::blst_map_to_g1(&mut point, &u.value, std::ptr::null());
+    }
+    G1Point { point }
+}
+
+/// Hash a message to a G2 point (RFC 9380 hash_to_curve, BLS12381G2_XMD:SHA-256_SSWU_RO_)
+///
+/// The result is uniformly distributed in the prime-order subgroup and deterministic per (msg, dst).
+#[wasm_bindgen]
+pub fn hash_to_g2(msg: &[u8], dst: &[u8]) -> G2Point {
+    let mut point = blst_p2::default();
+    unsafe {
+        blst::blst_hash_to_g2(
+            &mut point,
+            msg.as_ptr(),
+            msg.len(),
+            dst.as_ptr(),
+            dst.len(),
+            std::ptr::null(),
+            0,
+        );
+    }
+    G2Point { point }
+}
+
+/// Domain separation tag of the Ethereum BLS ciphersuite (minimal-pubkey-size, proof of possession)
+pub const ETH_BLS_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Verify a BLS signature whose public key (G1) and signature (G2) are EIP-2537 encoded
+#[wasm_bindgen]
+pub fn verify_eip2537(pubkey_bytes: &[u8], message: &[u8], signature_bytes: &[u8]) -> Result<bool, CryptoError> {
+    let pubkey = G1Point::from_eip2537_bytes(pubkey_bytes)?;
+    let signature = G2Point::from_eip2537_bytes(signature_bytes)?;
+    
+    if pubkey.is_infinity() {
+        return Err(CryptoError::PointAtInfinity);
+    }
+    
+    let mut pk_affine = blst_p1_affine::default();
+    let mut sig_affine = blst_p2_affine::default();
+    let result = unsafe {
+        blst::blst_p1_to_affine(&mut pk_affine, &pubkey.point);
+        blst::blst_p2_to_affine(&mut sig_affine, &signature.point);
+        blst::blst_core_verify_pk_in_g1(
+            &pk_affine,
+            &sig_affine,
+            true,
+            message.as_ptr(),
+            message.len(),
+            ETH_BLS_DST.as_ptr(),
+            ETH_BLS_DST.len(),
+            std::ptr::null(),
+            0,
+        )
+    };
+    
+    Ok(result == blst::BLST_ERROR::BLST_SUCCESS)
+}
+
+/// Size of one compressed G2 signature (as from `bls::Signature::to_bytes`), used by `aggregation_savings`
+const COMPRESSED_SIGNATURE_BYTES: usize = 96;
+
+/// Bandwidth saved by sending one aggregate signature instead of `num_signatures` individual ones
+#[wasm_bindgen]
+pub fn aggregation_savings(num_signatures: usize) -> JsValue {
+    serde_wasm_bindgen::to_value(&aggregation_savings_report(num_signatures)).unwrap()
+}
+
+/// `aggregation_savings` as JSON, for Rust callers
+pub fn aggregation_savings_report(num_signatures: usize) -> serde_json::Value {
+    let individual_bytes = num_signatures * COMPRESSED_SIGNATURE_BYTES;
+    let aggregate_bytes = if num_signatures == 0 { 0 } else { COMPRESSED_SIGNATURE_BYTES };
+    let savings_percent = if num_signatures == 0 {
+        0.0
+    } else {
+        100.0 * (1.0 - aggregate_bytes as f64 / individual_bytes as f64)
+    };
+    
+    serde_json::json!({
+        "individual_bytes": individual_bytes,
+        "aggregate_bytes": aggregate_bytes,
+        "savings_percent": savings_percent
+    })
+}
+
+/// Hash functions for cryptographic operations
+#[wasm_bindgen]
+pub struct HashFunctions;
+
+#[wasm_bindgen]
+impl HashFunctions {
+    /// SHA-256 hash
+    #[wasm_bindgen]
+    pub fn sha256(data: &[u8]) -> HashResult {
+        use sha2::{Sha256, Digest};
+        let mut hasher = Sha256::new();
+        hasher.update(data);
+        let result = hasher.finalize();
+        
+        HashResult {
+            hash: result.to_vec(),
+            algorithm: "SHA-256".to_string(),
+        }
+    }
+    
+    /// SHA-512 hash
+    #[wasm_bindgen]
+    pub fn sha512(data: &[u8]) -> HashResult {
+        use sha2::{Sha512, Digest};
+        let mut hasher = Sha512::new();
+        hasher.update(data);
+        let result = hasher.finalize();
+        
+        HashResult {
+            hash: result.to_vec(),
+            algorithm: "SHA-512".to_string(),
+        }
+    }
+    
+    /// BLAKE2b hash
+    #[wasm_bindgen]
+    pub fn blake2b(data: &[u8]) -> HashResult {
+        use blake2::{Blake2b512, Digest};
+        let mut hasher = Blake2b512::new();
+        hasher.update(data);
+        let result = hasher.finalize();
+        
+        HashResult {
+            hash: result.to_vec(),
+            algorithm: "BLAKE2b".to_string(),
+        }
+    }
+    
+    /// BLAKE2b hash with a digest length of 1 to 64 bytes, tagged e.g. "BLAKE2b-256"
+    #[wasm_bindgen]
+    pub fn blake2b_sized(data: &[u8], out_len: usize) -> Result<HashResult, CryptoError> {
+        use blake2::Blake2bVar;
+        use blake2::digest::{Update, VariableOutput};
+        if !(1..=64).contains(&out_len) {
+            return Err(CryptoError::InvalidParameter(format!(
+                "BLAKE2b output length must be 1 to 64 bytes, got {}",
+                out_len
+            )));
+        }
+        let mut hasher = Blake2bVar::new(out_len).expect("output length checked above");
+        hasher.update(data);
+        let mut result = vec![0u8; out_len];
+        hasher
+            .finalize_variable(&mut result)
+            .expect("buffer matches the configured output length");
+        
+        Ok(HashResult {
+            hash: result,
+            algorithm: format!("BLAKE2b-{}", out_len * 8),
+        })
+    }
+    
+    /// SHA3-256 hash (FIPS 202)
+    #[wasm_bindgen]
+    pub fn sha3_256(data: &[u8]) -> HashResult {
+        use sha3::{Sha3_256, Digest};
+        let mut hasher = Sha3_256::new();
+        hasher.update(data);
+        let result = hasher.finalize();
+        
+        HashResult {
+            hash: result.to_vec(),
+            algorithm: "SHA3-256".to_string(),
+        }
+    }
+    
+    /// Keccak-256 hash with the original Keccak padding, as used by Ethereum
+    #[wasm_bindgen]
+    pub fn keccak256(data: &[u8]) -> HashResult {
+        use sha3::{Keccak256, Digest};
+        let mut hasher = Keccak256::new();
+        hasher.update(data);
+        let result = hasher.finalize();
+        
+        HashResult {
+            hash: result.to_vec(),
+            algorithm: "Keccak-256".to_string(),
+        }
+    }
+    
+    /// SHAKE128 extendable-output function (FIPS 202) with an `out_len`-byte output
+    #[wasm_bindgen]
+    pub fn shake128(data: &[u8], out_len: usize) -> HashResult {
+        use sha3::digest::{ExtendableOutput, Update};
+        let mut hasher = sha3::Shake128::default();
+        hasher.update(data);
+        let mut result = vec![0u8; out_len];
+        hasher.finalize_xof_into(&mut result);
+        
+        HashResult {
+            hash: result,
+            algorithm: "SHAKE128".to_string(),
+        }
+    }
+    
+    /// SHAKE256 extendable-output function (FIPS 202) with an `out_len`-byte output
+    ///
+    /// Shorter outputs are prefixes of longer ones for the same input.
+    #[wasm_bindgen]
+    pub fn shake256(data: &[u8], out_len: usize) -> HashResult {
+        use sha3::digest::{ExtendableOutput, Update};
+        let mut hasher = sha3::Shake256::default();
+        hasher.update(data);
+        let mut result = vec![0u8; out_len];
+        hasher.finalize_xof_into(&mut result);
+        
+        HashResult {
+            hash: result,
+            algorithm: "SHAKE256".to_string(),
+        }
+    }
+    
+    /// BLAKE3 hash (32-byte output)
+    #[wasm_bindgen]
+    pub fn blake3(data: &[u8]) -> HashResult {
+        let result = blake3::hash(data);
+        
+        HashResult {
+            hash: result.as_bytes().to_vec(),
+            algorithm: "BLAKE3".to_string(),
+        }
+    }
+    
+    /// HMAC-SHA256 message authentication code (RFC 2104)
+    #[wasm_bindgen]
+    pub fn hmac_sha256(key: &[u8], data: &[u8]) -> HashResult {
+        use hmac::{Hmac, Mac};
+        let mut mac = Hmac::<sha2::Sha256>::new_from_slice(key)
+            .expect("HMAC accepts keys of any length");
+        mac.update(data);
+        let result = mac.finalize().into_bytes();
+        
+        HashResult {
+            hash: result.to_vec(),
+            algorithm: "HMAC-SHA256".to_string(),
+        }
+    }
+    
+    /// SHA-256 of each input in one call
+    #[wasm_bindgen]
+    pub fn sha256_batch(inputs: Vec<js_sys::Uint8Array>) -> Vec<HashResult> {
+        HashFunctions::batch(&copy_inputs(&inputs), HashFunctions::sha256)
+    }
+    
+    /// SHA-512 of each input in one call
+    #[wasm_bindgen]
+    pub fn sha512_batch(inputs: Vec<js_sys::Uint8Array>) -> Vec<HashResult> {
+        HashFunctions::batch(&copy_inputs(&inputs), HashFunctions::sha512)
+    }
+    
+    /// BLAKE2b of each input in one call
+    #[wasm_bindgen]
+    pub fn blake2b_batch(inputs: Vec<js_sys::Uint8Array>) -> Vec<HashResult> {
+        HashFunctions::batch(&copy_inputs(&inputs), HashFunctions::blake2b)
+    }
+    
+    /// SHA3-256 of each input in one call
+    #[wasm_bindgen]
+    pub fn sha3_256_batch(inputs: Vec<js_sys::Uint8Array>) -> Vec<HashResult> {
+        HashFunctions::batch(&copy_inputs(&inputs), HashFunctions::sha3_256)
+    }
+    
+    /// Keccak-256 of each input in one call
+    #[wasm_bindgen]
+    pub fn keccak256_batch(inputs: Vec<js_sys::Uint8Array>) -> Vec<HashResult> {
+        HashFunctions::batch(&copy_inputs(&inputs), HashFunctions::keccak256)
+    }
+    
+    /// BLAKE3 of each input in one call
+    #[wasm_bindgen]
+    pub fn blake3_batch(inputs: Vec<js_sys::Uint8Array>) -> Vec<HashResult> {
+        HashFunctions::batch(&copy_inputs(&inputs), HashFunctions::blake3)
+    }
+    
+    /// Convert hash to hex string
+    #[wasm_bindgen]
+    pub fn to_hex(hash: &HashResult) -> String {
+        hex::encode(&hash.hash)
+    }
+}
+
+impl HashFunctions {
+    /// Rust-side entry point for the `*_batch` functions: apply `hash` to every input
+    pub fn batch<M: AsRef<[u8]>>(inputs: &[M], hash: fn(&[u8]) -> HashResult) -> Vec<HashResult> {
+        inputs.iter().map(|data| hash(data.as_ref())).collect()
+    }
+}
+
+fn copy_inputs(inputs: &[js_sys::Uint8Array]) -> Vec<Vec<u8>> {
+    inputs.iter().map(|data| data.to_vec()).collect()
+}
+
+/// Incremental hasher for inputs that arrive in chunks
+///
+/// Supports the same algorithms as `HashFunctions`, selected by their algorithm tag.
+#[wasm_bindgen]
+pub struct StreamingHasher {
+    state: HasherState,
+}
+
+enum HasherState {
+    Sha256(sha2::Sha256),
+    Sha512(sha2::Sha512),
+    Blake2b(blake2::Blake2b512),
+    Sha3_256(sha3::Sha3_256),
+    Keccak256(sha3::Keccak256),
+    Blake3(Box<blake3::Hasher>),
+}
+
+#[wasm_bindgen]
+impl StreamingHasher {
+    /// Start a hasher for one of "SHA-256", "SHA-512", "BLAKE2b", "SHA3-256", "Keccak-256" or "BLAKE3"
+    #[wasm_bindgen(constructor)]
+    pub fn new(algorithm: &str) -> Result<StreamingHasher, CryptoError> {
+        use sha2::Digest;
+        let state = match algorithm {
+            "SHA-256" => HasherState::Sha256(sha2::Sha256::new()),
+            "SHA-512" => HasherState::Sha512(sha2::Sha512::new()),
+            "BLAKE2b" => HasherState::Blake2b(blake2::Blake2b512::new()),
+            "SHA3-256" => HasherState::Sha3_256(sha3::Sha3_256::new()),
+            "Keccak-256" => HasherState::Keccak256(sha3::Keccak256::new()),
+            "BLAKE3" => HasherState::Blake3(Box::new(blake3::Hasher::new())),
+            _ => return Err(CryptoError::InvalidParameter(format!("unsupported hash algorithm: {}", algorithm))),
+        };
+        Ok(StreamingHasher { state })
+    }
+    
+    /// Absorb the next chunk of input
+    #[wasm_bindgen]
+    pub fn update(&mut self, data: &[u8]) {
+        use sha2::Digest;
+        match &mut self.state {
+            HasherState::Sha256(h) => h.update(data),
+            HasherState::Sha512(h) => h.update(data),
+            HasherState::Blake2b(h) => h.update(data),
+            HasherState::Sha3_256(h) => h.update(data),
+            HasherState::Keccak256(h) => h.update(data),
+            HasherState::Blake3(h) => {
+                h.update(data);
+            }
+        }
+    }
+    
+    /// Consume the hasher and return the digest
+    #[wasm_bindgen]
+    pub fn finalize(self) -> HashResult {
+        use sha2::Digest;
+        let (hash, algorithm) = match self.state {
+            HasherState::Sha256(h) => (h.finalize().to_vec(), "SHA-256"),
+            HasherState::Sha512(h) => (h.finalize().to_vec(), "SHA-512"),
+            HasherState::Blake2b(h) => (h.finalize().to_vec(), "BLAKE2b"),
+            HasherState::Sha3_256(h) => (h.finalize().to_vec(), "SHA3-256"),
+            HasherState::Keccak256(h) => (h.finalize().to_vec(), "Keccak-256"),
+            HasherState::Blake3(h) => (h.finalize().as_bytes().to_vec(), "BLAKE3"),
+        };
+        
+        HashResult {
+            hash,
+            algorithm: algorithm.to_string(),
+        }
+    }
+}
+
+/// Build a SHA-256 hash chain: element i is the seed hashed i + 1 times
+#[wasm_bindgen]
+pub fn build_hash_chain(seed: &[u8], length: usize) -> Vec<HashResult> {
+    let mut chain: Vec<HashResult> = Vec::with_capacity(length);
+    for i in 0..length {
+        let next = match i {
+            0 => HashFunctions::sha256(seed),
+            _ => HashFunctions::sha256(&chain[i - 1].hash),
+        };
+        chain.push(next);
+    }
+    chain
+}
+
+/// Check that hashing the seed `length` times yields `final_value`, comparing in constant time
+#[wasm_bindgen]
+pub fn verify_hash_chain(seed: &[u8], length: usize, final_value: &HashResult) -> bool {
+    if length > 0 && final_value.algorithm != "SHA-256" {
+        return false;
+    }
+    
+    let mut current = seed.to_vec();
+    for _ in 0..length {
+        current = HashFunctions::sha256(&current).hash;
+    }
+    current.ct_eq(&final_value.hash).into()
+}
+
+/// Iterations per operation in `Benchmarks::run_benchmarks`
+const BENCHMARK_ITERATIONS: u32 = 1000;
+
+/// Time `iterations` calls of `op` with the high-resolution clock
+fn time_operation(operation: &str, iterations: u32, mut op: impl FnMut()) -> PerformanceMetrics {
+    let start = performance::now();
+    for _ in 0..iterations {
+        op();
+    }
+    let elapsed_ms = performance::now() - start;
+    
+    PerformanceMetrics {
+        operation: operation.to_string(),
+        iterations,
+        wasm_time_us: (elapsed_ms * 1000.0) as u64,
+        memory_used_kb: linear_memory_bytes() / 1024,
+    }
+}
+
+/// Size of a WebAssembly memory page
+const WASM_PAGE_BYTES: u64 = 65536;
+
+/// Current size of the WASM linear memory in bytes (zero on native targets)
+fn linear_memory_bytes() -> u64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use wasm_bindgen::JsCast;
+        let memory: js_sys::WebAssembly::Memory = wasm_bindgen::memory().unchecked_into();
+        memory.buffer().unchecked_into::<js_sys::ArrayBuffer>().byte_length() as u64
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        0
+    }
+}
+
+/// Performance benchmarking utilities
+#[wasm_bindgen]
+pub struct Benchmarks;
+
+#[wasm_bindgen]
+impl Benchmarks {
+    /// Run performance benchmarks
+    #[wasm_bindgen]
+    pub fn run_benchmarks() -> Result<Vec<JsValue>, CryptoError> {
+        Ok(Benchmarks::measure(BENCHMARK_ITERATIONS)?
+            .iter()
+            .map(|metrics| serde_wasm_bindgen::to_value(metrics).unwrap())
+            .collect())
+    }
+    
+    /// Time each benchmarked operation
+    ///
+    /// Cheap operations run `iterations` times; scalar multiplication and pairings
+    /// run 10x and 100x fewer times so the suite stays responsive in a browser.
+    #[wasm_bindgen]
+    pub fn measure(iterations: u32) -> Result<Vec<PerformanceMetrics>, CryptoError> {
+        let a = FpElement::random()?;
+        let b = FpElement::random()?;
+        let p = G1Point::random()?;
+        let q = G1Point::random()?;
+        let g2 = G2Point::random()?;
+        let scalar = Scalar::random()?;
+        let data = [0xabu8; 64];
+        
+        Ok(vec![
+            time_operation("Field Multiplication", iterations, || {
+                black_box(black_box(&a).mul(black_box(&b)));
+            }),
+            time_operation("G1 Addition", iterations, || {
+                black_box(black_box(&p).add(black_box(&q)));
+            }),
+            time_operation("G1 Scalar Multiplication", (iterations / 10).max(1), || {
+                black_box(black_box(&p).scalar_mul_fr(black_box(&scalar)));
+            }),
+            time_operation("Pairing", (iterations / 100).max(1), || {
+                black_box(pairing(black_box(&p), black_box(&g2)));
+            }),
+            time_operation("SHA-256", iterations, || {
+                black_box(HashFunctions::sha256(black_box(&data)));
+            }),
+        ])
+    }
+    
+    /// Export metrics as cargo-criterion `benchmark-complete` records (a JSON array)
+    #[wasm_bindgen]
+    pub fn to_criterion_json(metrics: Vec<JsValue>) -> Result<String, CryptoError> {
+        let metrics = metrics
+            .into_iter()
+            .map(|value| {
+                serde_wasm_bindgen::from_value::<PerformanceMetrics>(value)
+                    .map_err(|e| CryptoError::Serialization(format!("invalid metrics entry: {}", e)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Benchmarks::criterion_json(&metrics)
+    }
+    
+    /// Get memory usage info: the current size of the WASM linear memory
+    ///
+    /// Linear memory only ever grows, so this is the high-water mark of the heap.
+    #[wasm_bindgen]
+    pub fn get_memory_usage() -> JsValue {
+        serde_wasm_bindgen::to_value(&Benchmarks::memory_usage_report()).unwrap()
+    }
+}
+
+impl Benchmarks {
+    /// Rust-side counterpart of `to_criterion_json`
+    pub fn criterion_json(metrics: &[PerformanceMetrics]) -> Result<String, CryptoError> {
+        let records: Vec<serde_json::Value> = metrics.iter().map(|m| m.to_criterion_record()).collect();
+        serde_json::to_string(&records)
+            .map_err(|e| CryptoError::Serialization(e.to_string()))
+    }
+    
+    /// `get_memory_usage` as JSON, for Rust callers (all zero on native targets)
+    pub fn memory_usage_report() -> serde_json::Value {
+        let bytes = linear_memory_bytes();
+        serde_json::json!({
+            "heap_total_kb": bytes / 1024,
+            "memory_pages": bytes / WASM_PAGE_BYTES,
+            "memory_bytes": bytes
+        })
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct CryptoModule;
+
+#[wasm_bindgen]
+impl CryptoModule {
+    /// Initialize the crypto module
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> CryptoModule {
+        CryptoModule
+    }
+    
+    /// Get module version (the crate version from Cargo.toml)
+    #[wasm_bindgen]
+    pub fn version() -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+    
+    /// Check if all cryptographic functions are working
+    ///
+    /// Runs a deterministic known-answer check for each subsystem and reports
+    /// pass/fail per operation under `operations`.
+    #[wasm_bindgen]
+    pub fn self_test() -> Result<JsValue, CryptoError> {
+        serde_wasm_bindgen::to_value(&CryptoModule::self_test_report())
+            .map_err(|e| CryptoError::Serialization(e.to_string()))
+    }
+}
+
+impl CryptoModule {
+    /// Known-answer self-test results as JSON, see `self_test`
+    pub fn self_test_report() -> serde_json::Value {
+        let operations: serde_json::Map<String, serde_json::Value> = self_test::CHECKS
+            .iter()
+            .map(|(name, check)| (name.to_string(), serde_json::Value::Bool(check())))
+            .collect();
+        let passed = operations.values().all(|v| v.as_bool() == Some(true));
+        
+        serde_json::json!({
+            "status": if passed { "success" } else { "failure" },
+            "operations": operations
+        })
+    }
+}
+
+// Known-answer checks backing `CryptoModule::self_test`
+mod self_test {
+    use crate::*;
+    
+    type Check = fn() -> bool;
+    
+    pub const CHECKS: &[(&str, Check)] = &[
+        ("fp_arithmetic", fp_arithmetic),
+        ("scalar_arithmetic", scalar_arithmetic),
+        ("g1_serialization", g1_serialization),
+        ("g2_serialization", g2_serialization),
+        ("hash_functions", hash_functions),
+        ("hash_to_curve", hash_to_curve),
+        ("pairing", pairing_bilinearity),
+        ("bls_sign_verify", bls_sign_verify),
+    ];
+    
+    fn fp(v: u64) -> FpElement {
+        FpElement::new(&format!("{:096x}", v)).expect("valid field element")
+    }
+    
+    fn fp_arithmetic() -> bool {
+        let (two, three) = (fp(2), fp(3));
+        let inverse_ok = three
+            .inverse()
+            .map(|inv| three.mul(&inv).equals(&fp(1)))
+            .unwrap_or(false);
+        two.add(&three).equals(&fp(5)) && two.mul(&three).equals(&fp(6)) && inverse_ok
+    }
+    
+    fn scalar_arithmetic() -> bool {
+        let seven = Scalar::from_u64(7);
+        let inverse_ok = seven
+            .inverse()
+            .map(|inv| seven.mul(&inv).equals(&Scalar::from_u64(1)))
+            .unwrap_or(false);
+        seven.add(&seven.neg()).is_zero() && seven.sub(&Scalar::from_u64(3)).equals(&Scalar::from_u64(4)) && inverse_ok
+    }
+    
+    fn g1_serialization() -> bool {
+        const GENERATOR_COMPRESSED: &str = "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+        let p = G1Point::generator().scalar_mul_fr(&Scalar::from_u64(12345));
+        hex::encode(G1Point::generator().compress()) == GENERATOR_COMPRESSED
+            && G1Point::uncompress(&p.compress()).map(|q| q.equals(&p)).unwrap_or(false)
+            && G1Point::deserialize(&p.serialize()).map(|q| q.equals(&p)).unwrap_or(false)
+    }
+    
+    fn g2_serialization() -> bool {
+        let p = G2Point::generator().scalar_mul_fr(&Scalar::from_u64(12345));
+        G2Point::uncompress(&p.compress()).map(|q| q.equals(&p)).unwrap_or(false)
+            && G2Point::deserialize(&p.serialize()).map(|q| q.equals(&p)).unwrap_or(false)
+    }
+    
+    fn hash_functions() -> bool {
+        let vectors: [(HashResult, &str); 5] = [
+            (HashFunctions::sha256(b"abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+            (HashFunctions::sha3_256(b"abc"), "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"),
+            (HashFunctions::keccak256(b""), "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"),
+            (HashFunctions::blake3(b""), "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"),
+            (
+                HashFunctions::blake2b(b"abc"),
+                "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923",
+            ),
+        ];
+        vectors.iter().all(|(result, expected)| HashFunctions::to_hex(result) == *expected)
+    }
+    
+    fn hash_to_curve() -> bool {
+        // RFC 9380 appendix J.9.1, msg = ""
+        const DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+        const X: &str = "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1";
+        hex::encode(&hash_to_g1(b"", DST).serialize()[..48]) == X
+    }
+    
+    fn pairing_bilinearity() -> bool {
+        let (a, b) = (Scalar::from_u64(6), Scalar::from_u64(7));
+        let p = G1Point::generator();
+        let q = G2Point::generator();
+        pairing_check(
+            vec![p.scalar_mul_fr(&a), p.scalar_mul_fr(&a.mul(&b)).negate()],
+            vec![q.scalar_mul_fr(&b), q],
+        )
+    }
+    
+    fn bls_sign_verify() -> bool {
+        let Ok(sk) = bls::keygen(&[0x42; 32]) else {
+            return false;
+        };
+        let pk = sk.public_key();
+        let sig = sk.sign(b"self-test", ETH_BLS_DST);
+        bls::verify(&pk, b"self-test", &sig, ETH_BLS_DST) && !bls::verify(&pk, b"self-tesT", &sig, ETH_BLS_DST)
+    }
+}
+
+// Performance tracking: `performance.now()` in the browser, a monotonic clock elsewhere
+mod performance {
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen::prelude::*;
+    
+    #[cfg(target_arch = "wasm32")]
+    #[wasm_bindgen]
+    extern "C" {
+        /// Milliseconds with sub-millisecond resolution
+        #[wasm_bindgen(js_namespace = performance)]
+        pub fn now() -> f64;
+    }
+    
+    /// Milliseconds since the first call
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn now() -> f64 {
+        use std::sync::OnceLock;
+        use std::time::Instant;
+        
+        static START: OnceLock<Instant> = OnceLock::new();
+        START.get_or_init(Instant::now).elapsed().as_secs_f64() * 1000.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// G·2^k by repeated doubling, independent of any scalar multiplication
+    fn g1_pow2(k: usize) -> G1Point {
+        (0..k).fold(G1Point::generator(), |p, _| p.double())
+    }
+    
+    #[test]
+    fn scalar_mul_top_bit_at_255_and_256_bits() {
+        let g = G1Point::generator();
+        let top = format!("80{}", "00".repeat(31));
+        
+        assert!(g.scalar_mul_bits(&top, 256).unwrap().equals(&g1_pow2(255)));
+        assert!(g.scalar_mul(&top).unwrap().equals(&g1_pow2(255)));
+        // At 255 bits the top bit lies outside the scalar and is ignored
+        assert!(g.scalar_mul_bits(&top, 255).unwrap().is_infinity());
+        
+        let top_le = format!("{}80", "00".repeat(31));
+        assert!(g.scalar_mul_le(&top_le, 256).unwrap().equals(&g1_pow2(255)));
+        assert!(g.scalar_mul_le(&top_le, 255).unwrap().is_infinity());
+        
+        // Bit 254 survives both lengths
+        let next = format!("40{}", "00".repeat(31));
+        assert!(g.scalar_mul_bits(&next, 255).unwrap().equals(&g1_pow2(254)));
+        assert!(g.scalar_mul_bits(&next, 256).unwrap().equals(&g1_pow2(254)));
+    }
+    
+    #[test]
+    fn scalar_mul_requires_32_bytes() {
+        let g1 = G1Point::generator();
+        let g2 = G2Point::generator();
+        for len in [31, 33, 64] {
+            let bytes = vec![1u8; len];
+            let expected = CryptoError::WrongLength { expected: 32, got: len };
+            assert_eq!(g1.scalar_mul(&hex::encode(&bytes)).unwrap_err(), expected);
+            assert_eq!(g1.scalar_mul_bytes(&bytes).unwrap_err(), expected);
+            assert_eq!(g2.scalar_mul(&hex::encode(&bytes)).unwrap_err(), expected);
+            assert_eq!(g2.scalar_mul_bytes(&bytes).unwrap_err(), expected);
+        }
+        
+        let five = format!("{:0>64}", "05");
+        let expected = g1.add(&g1).add(&g1).add(&g1).add(&g1);
+        assert!(g1.scalar_mul(&five).unwrap().equals(&expected));
+        let expected = g2.add(&g2).add(&g2).add(&g2).add(&g2);
+        assert!(g2.scalar_mul(&five).unwrap().equals(&expected));
+    }
+    
+    fn fp(v: u64) -> FpElement {
+        FpElement::new(&format!("{:0>96x}", v)).unwrap()
+    }
+    
+    #[test]
+    fn legendre_symbol() {
+        assert_eq!(fp(4).legendre(), 1);
+        assert_eq!(fp(9).legendre(), 1);
+        // p ≡ 3 (mod 8), so both -1 and 2 are non-residues
+        assert_eq!(fp(1).neg().legendre(), -1);
+        assert_eq!(fp(2).legendre(), -1);
+        assert_eq!(fp(0).legendre(), 0);
+        
+        let x = FpElement::random_from_seed(b"legendre");
+        assert_eq!(x.mul(&x).legendre(), 1);
+        assert_eq!(x.mul(&x).neg().legendre(), -1);
+    }
+    
+    #[test]
+    fn g2_group_identities() {
+        let p = G2Point::random_from_seed(b"g2 p");
+        let q = G2Point::random_from_seed(b"g2 q");
+        
+        assert!(p.add(&p.negate()).is_infinity());
+        assert!(p.double().equals(&p.add(&p)));
+        assert!(p.subtract(&q).add(&q).equals(&p));
+        assert!(p.subtract(&p).is_infinity());
+        assert!(G2Point::identity().double().is_infinity());
+        assert!(G2Point::identity().negate().is_infinity());
+    }
+    
+    /// The group order r as big-endian hex
+    const R_HEX: &str = "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001";
+    
+    #[test]
+    fn scalar_new_reduces_mod_r() {
+        assert!(Scalar::new(R_HEX).unwrap().is_zero());
+        
+        let r_plus_5 = "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000006";
+        assert!(Scalar::new(r_plus_5).unwrap().equals(&Scalar::from_u64(5)));
+        
+        // 2^256 - 1 mod r
+        let reduced = Scalar::new(&"ff".repeat(32)).unwrap();
+        assert_eq!(reduced.to_hex(), "1824b159acc5056f998c4fefecbc4ff55884b7fa0003480200000001fffffffd");
+        
+        let r_minus_1 = "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000";
+        assert_eq!(Scalar::new(r_minus_1).unwrap().to_hex(), r_minus_1);
+        
+        assert!(matches!(Scalar::new("zz"), Err(CryptoError::InvalidHex(_))));
+        assert_eq!(Scalar::new("01").unwrap_err(), CryptoError::WrongLength { expected: 32, got: 1 });
+    }
+    
+    /// A scalar is canonical when its encoding is below r, which `from_bytes` enforces
+    fn is_canonical(s: &Scalar) -> bool {
+        Scalar::from_bytes(&s.to_bytes()).is_ok()
+    }
+    
+    #[test]
+    fn scalar_field_axioms() {
+        for i in 0..8u8 {
+            let a = Scalar::random_from_seed(&[i, 1]);
+            let b = Scalar::random_from_seed(&[i, 2]);
+            let c = Scalar::random_from_seed(&[i, 3]);
+            
+            assert!(a.add(&b).add(&c).equals(&a.add(&b.add(&c))));
+            assert!(a.mul(&b).mul(&c).equals(&a.mul(&b.mul(&c))));
+            assert!(a.mul(&b.add(&c)).equals(&a.mul(&b).add(&a.mul(&c))));
+            assert!(a.add(&b).equals(&b.add(&a)));
+            assert!(a.sub(&b).add(&b).equals(&a));
+            assert!(a.add(&a.neg()).is_zero());
+            
+            for s in [a.add(&b), a.sub(&b), a.mul(&b), a.neg()] {
+                assert!(is_canonical(&s));
+            }
+        }
+        
+        // Wrapping around r stays canonical
+        let r_minus_1 = Scalar::from_u64(1).neg();
+        assert!(r_minus_1.add(&Scalar::from_u64(1)).is_zero());
+        assert!(Scalar::from_u64(0).sub(&Scalar::from_u64(1)).equals(&r_minus_1));
+        assert!(Scalar::from_u64(0).neg().is_zero());
+    }
+    
+    #[test]
+    fn scalar_inverse() {
+        for i in 0..8u8 {
+            let s = Scalar::random_from_seed(&[i, 4]);
+            assert!(s.mul(&s.inverse().unwrap()).equals(&Scalar::from_u64(1)));
+        }
+        assert!(Scalar::from_u64(1).inverse().unwrap().equals(&Scalar::from_u64(1)));
+        assert_eq!(Scalar::from_u64(0).inverse().unwrap_err(), CryptoError::ZeroInverse);
+        assert_eq!(Scalar::new(R_HEX).unwrap().inverse().unwrap_err(), CryptoError::ZeroInverse);
+    }
+    
+    #[test]
+    #[cfg(not(feature = "failing-rng"))]
+    fn scalar_random_is_canonical_and_nonzero() {
+        let draws: Vec<Scalar> = (0..32).map(|_| Scalar::random().unwrap()).collect();
+        for s in &draws {
+            assert!(!s.is_zero());
+            assert!(is_canonical(s));
+        }
+        assert!(!draws[0].equals(&draws[1]));
+    }
+    
+    #[test]
+    fn scalar_bytes_round_trip() {
+        for i in 0..8u8 {
+            let s = Scalar::random_from_seed(&[i, 5]);
+            let bytes = s.to_bytes();
+            assert_eq!(bytes.len(), 32);
+            assert!(Scalar::from_bytes(&bytes).unwrap().equals(&s));
+        }
+        
+        // Little-endian: from_u64(258) is 0x02, 0x01, then zeros
+        let bytes = Scalar::from_u64(258).to_bytes();
+        assert_eq!(&bytes[..3], &[0x02, 0x01, 0x00]);
+        
+        let mut r_le = hex::decode(R_HEX).unwrap();
+        r_le.reverse();
+        assert!(matches!(Scalar::from_bytes(&r_le), Err(CryptoError::NonCanonical(_))));
+        assert_eq!(Scalar::from_bytes(&[0u8; 31]).unwrap_err(), CryptoError::WrongLength { expected: 32, got: 31 });
+    }
+    
+    #[test]
+    fn scalar_from_u64_one_is_identity() {
+        let g = G1Point::generator();
+        assert!(g.scalar_mul_fr(&Scalar::from_u64(1)).equals(&g));
+        assert!(g.scalar_mul_fr(&Scalar::from_u64(2)).equals(&g.double()));
+        assert!(g.scalar_mul_fr(&Scalar::from_u64(0)).is_infinity());
+        
+        let s = Scalar::random_from_seed(b"identity");
+        assert!(s.mul(&Scalar::from_u64(1)).equals(&s));
+    }
+    
+    fn msm_inputs(n: usize, tag: u8) -> (Vec<G1Point>, Vec<Scalar>) {
+        let points = (0..n).map(|i| G1Point::random_from_seed(&[tag, 0, i as u8, (i >> 8) as u8])).collect();
+        let scalars = (0..n).map(|i| Scalar::random_from_seed(&[tag, 1, i as u8, (i >> 8) as u8])).collect();
+        (points, scalars)
+    }
+    
+    #[test]
+    fn g1_msm_matches_naive_sum() {
+        let (points, scalars) = msm_inputs(16, 1);
+        let naive = points
+            .iter()
+            .zip(&scalars)
+            .fold(G1Point::identity(), |acc, (p, s)| acc.add(&p.scalar_mul_fr(s)));
+        assert!(g1_msm(points, scalars).unwrap().equals(&naive));
+    }
+    
+    #[test]
+    fn g1_msm_edge_cases() {
+        assert!(g1_msm(vec![], vec![]).unwrap().is_infinity());
+        
+        let (points, scalars) = msm_inputs(3, 2);
+        assert_eq!(
+            g1_msm(points, scalars[..2].to_vec()).unwrap_err(),
+            CryptoError::LengthMismatch { left: 3, right: 2 }
+        );
+    }
+    
+    #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+    #[test]
+    fn g1_msm_parallel_matches_serial() {
+        // Four threads split 1030 points into chunks of 258, above PARALLEL_MSM_MIN_CHUNK
+        // with a shorter last chunk; a single thread keeps them in one serial chunk
+        let (points, scalars) = msm_inputs(1030, 3);
+        let pool = |threads| rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+        let parallel = pool(4).install(|| g1_msm(points.clone(), scalars.clone()).unwrap());
+        let serial = pool(1).install(|| g1_msm(points, scalars).unwrap());
+        assert!(parallel.equals(&serial));
+    }
+    
+    #[test]
+    fn hash_to_g2_rfc9380_vector() {
+        // RFC 9380 appendix J.10.1, BLS12381G2_XMD:SHA-256_SSWU_RO_ with msg = ""
+        let expected = G2Point::from_coordinates(
+            "0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a",
+            "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d",
+            "0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92",
+            "12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6",
+        )
+        .unwrap();
+        let point = hash_to_g2(b"", b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_");
+        assert!(point.equals(&expected));
+    }
+    
+    #[test]
+    fn sha3_256_nist_vectors() {
+        // FIPS 202 examples for the empty message and "abc"
+        assert_eq!(
+            HashFunctions::to_hex(&HashFunctions::sha3_256(b"")),
+            "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"
+        );
+        assert_eq!(
+            HashFunctions::to_hex(&HashFunctions::sha3_256(b"abc")),
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+        );
+    }
+    
+    #[test]
+    fn keccak256_vectors_differ_from_sha3() {
+        // Keccak-256 pads with 0x01 where SHA3-256 uses 0x06, so the same input hashes differently
+        let vectors = [
+            (&b""[..], "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"),
+            (&b"abc"[..], "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"),
+        ];
+        for (input, expected) in vectors {
+            let keccak = HashFunctions::to_hex(&HashFunctions::keccak256(input));
+            assert_eq!(keccak, expected);
+            assert_ne!(keccak, HashFunctions::to_hex(&HashFunctions::sha3_256(input)));
+        }
+    }
+    
+    #[test]
+    fn blake3_reference_vectors() {
+        // test_vectors.json from the BLAKE3 reference implementation, whose inputs are
+        // the repeating byte sequence 0, 1, ..., 250 truncated to each length
+        let vectors = [
+            (0, "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"),
+            (1, "2d3adedff11b61f14c886e35afa036736dcd87a74d27b5c1510225d0f592e213"),
+            (1024, "42214739f095a406f3fc83deb889744ac00df831c10daa55189b5d121c855af7"),
+        ];
+        for (len, expected) in vectors {
+            let input: Vec<u8> = (0..len).map(|i| (i % 251) as u8).collect();
+            assert_eq!(HashFunctions::to_hex(&HashFunctions::blake3(&input)), expected);
+        }
+    }
+    
+    #[test]
+    fn hmac_sha256_rfc4231_vectors() {
+        // RFC 4231 test cases 1-4, 6 and 7; case 5 only checks a truncated tag
+        let large_key = [0xaa; 131];
+        let vectors: [(&[u8], &[u8], &str); 6] = [
+            (
+                &[0x0b; 20],
+                b"Hi There",
+                "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+            ),
+            (
+                b"Jefe",
+                b"what do ya want for nothing?",
+                "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+            ),
+            (
+                &[0xaa; 20],
+                &[0xdd; 50],
+                "773ea91e36800e46854db8ebd09181a72959098b3ef8c122d9635514ced565fe",
+            ),
+            (
+                &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25],
+                &[0xcd; 50],
+                "82558a389a443c0ea4cc819899f2083a85f0faa3e578f8077a2e3ff46729665b",
+            ),
+            (
+                &large_key,
+                b"Test Using Larger Than Block-Size Key - Hash Key First",
+                "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+            ),
+            (
+                &large_key,
+                b"This is a test using a larger than block-size key and a larger than block-size data. \
+                  The key needs to be hashed before being used by the HMAC algorithm.",
+                "9b09ffa71b942fcb27635fbcd5b0e944bfdc63644f0713938a7f51535c3a35e2",
+            ),
+        ];
+        for (key, data, expected) in vectors {
+            assert_eq!(HashFunctions::to_hex(&HashFunctions::hmac_sha256(key, data)), expected);
+        }
+    }
+    
+    #[test]
+    fn version_is_the_package_semver() {
+        let version = CryptoModule::version();
+        assert_eq!(version, env!("CARGO_PKG_VERSION"));
+        
+        // MAJOR.MINOR.PATCH, optionally followed by -pre-release or +build metadata
+        let core = version.split(['-', '+']).next().unwrap();
+        let parts: Vec<&str> = core.split('.').collect();
+        assert_eq!(parts.len(), 3, "{version} is not MAJOR.MINOR.PATCH");
+        assert!(parts.iter().all(|part| part.parse::<u64>().is_ok()), "{version} has a non-numeric part");
+    }
+    
+    #[test]
+    fn wrong_length_inputs_map_to_wrong_length() {
+        let expected = CryptoError::WrongLength { expected: 32, got: 31 };
+        assert_eq!(Scalar::new(&"01".repeat(31)).unwrap_err(), expected);
+        assert_eq!(Scalar::from_bytes(&[1u8; 31]).unwrap_err(), expected);
+        assert_eq!(expected.code(), "WRONG_LENGTH");
+        
+        assert!(matches!(
+            G1Point::uncompress(&[0u8; 47]),
+            Err(CryptoError::WrongLength { expected: 48, got: 47 })
+        ));
+        assert!(matches!(
+            G2Point::uncompress(&[0u8; 97]),
+            Err(CryptoError::WrongLength { expected: 96, got: 97 })
+        ));
+    }
+    
+    #[cfg(feature = "failing-rng")]
+    #[test]
+    fn random_reports_rng_failure() {
+        assert!(matches!(G1Point::random(), Err(CryptoError::RngUnavailable(_))));
+        assert!(matches!(G2Point::random(), Err(CryptoError::RngUnavailable(_))));
+        assert!(matches!(FpElement::random(), Err(CryptoError::RngUnavailable(_))));
+        assert!(matches!(Scalar::random(), Err(CryptoError::RngUnavailable(_))));
+    }
+    
+    #[derive(Debug, Serialize, Deserialize)]
+    struct SerdePoints {
+        g1: G1Point,
+        g2: G2Point,
+        infinity: G1Point,
+    }
+    
+    /// Compressed G1 encoding of x = `x` with the compression flag set
+    fn compressed_g1_with_x(x: u8) -> Vec<u8> {
+        let mut bytes = vec![0u8; 48];
+        bytes[0] = 0x80;
+        bytes[47] = x;
+        bytes
+    }
+    
+    #[test]
+    fn serde_round_trip() {
+        let points = SerdePoints {
+            g1: G1Point::random_from_seed(b"serde g1"),
+            g2: G2Point::random_from_seed(b"serde g2"),
+            infinity: G1Point::identity(),
+        };
+        let check = |decoded: SerdePoints| {
+            assert!(decoded.g1.equals(&points.g1));
+            assert!(decoded.g2.equals(&points.g2));
+            assert!(decoded.infinity.is_infinity());
+        };
+        
+        let bytes = bincode::serialize(&points).unwrap();
+        check(bincode::deserialize(&bytes).unwrap());
+        
+        let json = serde_json::to_string(&points).unwrap();
+        assert!(json.contains(&hex::encode(points.g1.compress())));
+        check(serde_json::from_str(&json).unwrap());
+    }
+    
+    #[test]
+    fn serde_rejects_invalid_points() {
+        // x = 1 has no point on the curve; x = 4 gives a point outside the subgroup
+        for (x, error) in [(1, CryptoError::NotOnCurve), (4, CryptoError::NotInSubgroup)] {
+            let bytes = compressed_g1_with_x(x);
+            
+            let json = format!("\"{}\"", hex::encode(&bytes));
+            let err = serde_json::from_str::<G1Point>(&json).unwrap_err();
+            assert!(err.to_string().contains(&error.to_string()));
+            
+            let encoded = bincode::serialize(&bytes).unwrap();
+            let err = bincode::deserialize::<G1Point>(&encoded).unwrap_err();
+            assert!(err.to_string().contains(&error.to_string()));
+        }
+    }
+    
+    #[test]
+    fn map_to_g1_rfc9380_vector() {
+        // RFC 9380 appendix J.9.2, BLS12381G1_XMD:SHA-256_SSWU_NU_ with msg = "": u from
+        // hash_to_field and the final encode_to_curve output P
+        let u = FpElement::new(
+            "156c8a6a2c184569d69a76be144b5cdc5141d2d2ca4fe341f011e25e3969c55ad9e9b9ce2eb833c81a908e5fa4ac5f03",
+        )
+        .unwrap();
+        let expected = G1Point::from_coordinates(
+            "184bb665c37ff561a89ec2122dd343f20e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba",
+            "04407b8d35af4dacc809927071fc0405218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3",
+        )
+        .unwrap();
+        
+        assert!(map_to_g1(&u).equals(&expected));
+        assert!(encode_to_g1(b"", b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_NU_").equals(&expected));
+    }
+    
+    #[test]
+    fn lagrange_interpolate_recovers_polynomial() {
+        // p(x) = 5 + 3x + 2x^2
+        let p = |x: u64| Scalar::from_u64(5 + 3 * x + 2 * x * x);
+        let xs: Vec<Scalar> = [1, 2, 4].iter().map(|&x| Scalar::from_u64(x)).collect();
+        let ys: Vec<Scalar> = [1, 2, 4].iter().map(|&x| p(x)).collect();
+        for x in [0, 1, 3, 10] {
+            let value = lagrange_interpolate(xs.clone(), ys.clone(), &Scalar::from_u64(x)).unwrap();
+            assert!(value.equals(&p(x)));
+        }
+    }
+    
+    #[test]
+    fn lagrange_interpolate_errors() {
+        let one = Scalar::from_u64(1);
+        let duplicate_xs = vec![one.clone(), Scalar::from_u64(2), one.clone()];
+        let ys = vec![one.clone(), one.clone(), one.clone()];
+        assert_eq!(lagrange_interpolate(duplicate_xs, ys.clone(), &one).unwrap_err(), CryptoError::ZeroInverse);
+        assert_eq!(
+            lagrange_interpolate(vec![one.clone()], ys, &one).unwrap_err(),
+            CryptoError::LengthMismatch { left: 1, right: 3 }
+        );
+        assert_eq!(lagrange_interpolate(vec![], vec![], &one).unwrap_err(), CryptoError::EmptyInput);
+    }
+    
+    #[test]
+    fn mul_add_matches_separate_muls() {
+        let one = Scalar::from_u64(1);
+        let lambda = Scalar::new(&format!("{:0>64}", hex::encode(GLV_LAMBDA))).unwrap();
+        let mut scalars = vec![
+            Scalar::from_u64(0),
+            one.clone(),
+            Scalar::from_u64(0).sub(&one),
+            lambda.clone(),
+            lambda.add(&one),
+            lambda.sub(&one),
+        ];
+        scalars.extend((0..8u8).map(|i| Scalar::random_from_seed(&[b'm', i])));
+        
+        let p = G1Point::random_from_seed(b"mul_add p");
+        let q = G1Point::random_from_seed(b"mul_add q");
+        for s1 in &scalars {
+            for s2 in &scalars {
+                let expected = p.scalar_mul_fr(s1).add(&q.scalar_mul_fr(s2));
+                assert!(p.mul_add(s1, &q, s2).equals(&expected));
+            }
+        }
+    }
+    
+    /// (2, y) is on E2 but outside the prime-order subgroup
+    fn off_subgroup_g2() -> G2Point {
+        G2Point::from_curve_coordinates(
+            &format!("{:0>96}", "2"),
+            &format!("{:0>96}", "0"),
+            "013a59858b6809fca4d9a3b6539246a70051a3c88899964a42bc9a69cf9acdd9dd387cfa9086b894185b9a46a402be73",
+            "02d27e0ec3356299a346a09ad7dc4ef68a483c3aed53f9139d2f929a3eecebf72082e5e58c6da24ee32e03040c406d4f",
+        )
+        .unwrap()
+    }
+    
+    #[test]
+    fn g2_clear_cofactor_multiplies_by_h_eff() {
+        // RFC 9380 section 8.8.2
+        const H_EFF: &str = "0bc69f08f2ee75b3584c6a0ea91b352888e2a8e9145ad7689986ff031508ffe1329c2f178731db956d82bf015d\
+                             1212b02ec0ec69d7477c1ae954cbc06689f6a359894c0adebbf6b4e8020005aaa95551";
+        let h_eff = hex::decode(H_EFF).unwrap().into_iter().rev().collect::<Vec<u8>>();
+        
+        let points = [off_subgroup_g2(), off_subgroup_g2().double(), G2Point::random_from_seed(b"in subgroup")];
+        for p in points {
+            let cleared = p.clear_cofactor();
+            assert!(cleared.is_in_subgroup());
+            
+            let mut expected = blst_p2::default();
+            unsafe {
+                blst::blst_p2_mult(&mut expected, &p.point, h_eff.as_ptr(), 636);
+            }
+            assert!(cleared.equals(&G2Point { point: expected }));
+        }
+        assert!(!off_subgroup_g2().is_in_subgroup());
+    }
+    
+    #[test]
+    fn scalar_mul_le_reads_the_reversed_hex() {
+        // scalar_mul reads big-endian hex; scalar_mul_le of the byte-reversed hex is the same point
+        let be = format!("{:0>64}", "0102");
+        let le = format!("0201{:0>60}", "");
+        let g1 = G1Point::generator();
+        let g2 = G2Point::generator();
+        assert!(g1.scalar_mul(&be).unwrap().equals(&g1.scalar_mul_le(&le, 256).unwrap()));
+        assert!(g2.scalar_mul(&be).unwrap().equals(&g2.scalar_mul_le(&le, 256).unwrap()));
+        assert!(g2.scalar_mul(&be).unwrap().equals(&g2.scalar_mul_fr(&Scalar::from_u64(0x0102))));
+    }
+    
+    #[test]
+    fn aggregation_savings_for_100_signatures() {
+        let sig = bls::Signature::from_point(&G2Point::generator()).unwrap();
+        assert_eq!(sig.to_bytes().len(), COMPRESSED_SIGNATURE_BYTES);
+        
+        let report = aggregation_savings_report(100);
+        assert_eq!(report["individual_bytes"], 9600);
+        assert_eq!(report["aggregate_bytes"], 96);
+        assert_eq!(report["savings_percent"], 99.0);
+        assert_eq!(aggregation_savings_report(0)["aggregate_bytes"], 0);
+    }
+    
+    #[test]
+    fn g2_msm_matches_naive_sum() {
+        let points: Vec<G2Point> = (0..16u8).map(|i| G2Point::random_from_seed(&[b'g', i])).collect();
+        let scalars: Vec<Scalar> = (0..16u8).map(|i| Scalar::random_from_seed(&[b's', i])).collect();
+        let naive = points
+            .iter()
+            .zip(&scalars)
+            .fold(G2Point::identity(), |acc, (p, s)| acc.add(&p.scalar_mul_fr(s)));
+        assert!(g2_msm(points.clone(), scalars.clone()).unwrap().equals(&naive));
+        
+        assert!(g2_msm(vec![], vec![]).unwrap().is_infinity());
+        assert_eq!(
+            g2_msm(points, scalars[..3].to_vec()).unwrap_err(),
+            CryptoError::LengthMismatch { left: 16, right: 3 }
+        );
+    }
+    
+    #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+    #[test]
+    fn g2_msm_parallel_matches_serial() {
+        // Same split as g1_msm_parallel_matches_serial: four chunks of 258 points
+        let points: Vec<G2Point> = (0..1030u16).map(|i| G2Point::random_from_seed(&i.to_le_bytes())).collect();
+        let scalars: Vec<Scalar> = (0..1030u16).map(|i| Scalar::random_from_seed(&i.to_be_bytes())).collect();
+        let pool = |threads| rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+        let parallel = pool(4).install(|| g2_msm(points.clone(), scalars.clone()).unwrap());
+        let serial = pool(1).install(|| g2_msm(points, scalars).unwrap());
+        assert!(parallel.equals(&serial));
+    }
+    
+    #[test]
+    fn fp2_bytes_hex_and_components_agree() {
+        let c0 = FpElement::random_from_seed(b"fp2 c0");
+        let c1 = FpElement::random_from_seed(b"fp2 c1");
+        let bytes = [hex::decode(c0.to_hex()).unwrap(), hex::decode(c1.to_hex()).unwrap()].concat();
+        
+        let from_bytes = Fp2Element::from_bytes(&bytes).unwrap();
+        let from_hex = Fp2Element::from_hex(&hex::encode(&bytes)).unwrap();
+        let from_parts = Fp2Element::new(&c0, &c1);
+        for element in [&from_hex, &from_parts] {
+            assert!(element.c0().equals(&from_bytes.c0()));
+            assert!(element.c1().equals(&from_bytes.c1()));
+        }
+        assert!(from_bytes.c0().equals(&c0) && from_bytes.c1().equals(&c1));
+        
+        assert_eq!(
+            Fp2Element::from_bytes(&bytes[..95]).unwrap_err(),
+            CryptoError::WrongLength { expected: 96, got: 95 }
+        );
+    }
+    
+    #[test]
+    fn multi_pairing_matches_product_of_pairings() {
+        let g1s = vec![
+            G1Point::random_from_seed(b"multi-pairing p1"),
+            G1Point::random_from_seed(b"multi-pairing p2"),
+            G1Point::identity(),
+        ];
+        let g2s = vec![
+            G2Point::random_from_seed(b"multi-pairing q1"),
+            G2Point::random_from_seed(b"multi-pairing q2"),
+            G2Point::random_from_seed(b"multi-pairing q3"),
+        ];
+        
+        // The identity pair contributes e(O, Q) = 1 to the product
+        assert!(pairing(&g1s[2], &g2s[2]).equals(&GtElement::one()));
+        let expected = pairing(&g1s[0], &g2s[0]).mul(&pairing(&g1s[1], &g2s[1]));
+        assert!(multi_pairing(g1s.clone(), g2s.clone()).unwrap().equals(&expected));
+        
+        assert_eq!(
+            multi_pairing(g1s, g2s[..2].to_vec()).unwrap_err(),
+            CryptoError::LengthMismatch { left: 3, right: 2 }
+        );
+    }
+    
+    #[test]
+    fn pairing_check_accepts_cancelling_pairs() {
+        let p = G1Point::random_from_seed(b"pairing-check p");
+        let q = G2Point::random_from_seed(b"pairing-check q");
+        
+        assert!(pairing_check(vec![p, p.negate()], vec![q, q]));
+        assert!(!pairing_check(vec![p, p], vec![q, q]));
+        assert!(pairing_check(vec![], vec![]));
+        // Mismatched lengths are a failed check rather than an error
+        assert!(!pairing_check(vec![p, p.negate()], vec![q]));
+    }
+    
+    #[test]
+    fn pairing_of_generators_known_value() {
+        // e(G1, G2) from an independent optimal ate implementation, cubed and conjugated:
+        // blst's final exponentiation raises to 3(p^4 - p^2 + 1)/r and the BLS parameter
+        // x is negative. Components in blst_bendian_from_fp12 order
+        let expected = GtElement::from_hex(concat!(
+            "1250ebd871fc0a92a7b2d83168d0d727272d441befa15c503dd8e90ce98db3e7b6d194f60839c508a84305aaca1789b6",
+            "089a1c5b46e5110b86750ec6a532348868a84045483c92b7af5af689452eafabf1a8943e50439f1d59882a98eaa0170f",
+            "19f26337d205fb469cd6bd15c3d5a04dc88784fbb3d0b2dbdea54d43b2b73f2cbb12d58386a8703e0f948226e47ee89d",
+            "06fba23eb7c5af0d9f80940ca771b6ffd5857baaf222eb95a7d2809d61bfe02e1bfd1b68ff02f0b8102ae1c2d5d5ab1a",
+            "1368bb445c7c2d209703f239689ce34c0378a68e72a6b3b216da0e22a5031b54ddff57309396b38c881c4c849ec23e87",
+            "193502b86edb8857c273fa075a50512937e0794e1e65a7617c90d8bd66065b1fffe51d7a579973b1315021ec3c19934f",
+            "11b8b424cd48bf38fcef68083b0b0ec5c81a93b330ee1a677d0d15ff7b984e8978ef48881e32fac91b93b47333e2ba57",
+            "03350f55a7aefcd3c31b4fcb6ce5771cc6a0e9786ab5973320c806ad360829107ba810c5a09ffdd9be2291a0c25a99a2",
+            "01b2f522473d171391125ba84dc4007cfbf2f8da752f7c74185203fcca589ac719c34dffbbaad8431dad1c1fb597aaa5",
+            "018107154f25a764bd3c79937a45b84546da634b8f6be14a8061e55cceba478b23f7dacaa35c8ca78beae9624045b4b6",
+            "04c581234d086a9902249b64728ffd21a189e87935a954051c7cdba7b3872629a4fafc05066245cb9108f0242d0fe3ef",
+            "0f41e58663bf08cf068672cbd01a7ec73baca4d72ca93544deff686bfd6df543d48eaa24afe47e1efde449383b676631",
+        ))
+        .unwrap();
+        let g1 = G1Point::generator();
+        let g2 = G2Point::generator();
+        
+        let e = pairing(&g1, &g2);
+        assert!(e.equals(&expected));
+        assert!(pairing(&g1, &g2).equals(&e));
+        assert!(!e.equals(&GtElement::one()));
+        assert!(e.pow(R_HEX).unwrap().equals(&GtElement::one()));
+    }
+    
+    fn random_gt(tag: &[u8]) -> GtElement {
+        pairing(
+            &G1Point::random_from_seed(&[tag, b" g1"].concat()),
+            &G2Point::random_from_seed(&[tag, b" g2"].concat()),
+        )
+    }
+    
+    #[test]
+    fn gt_mul_is_associative_with_identity() {
+        let (a, b, c) = (random_gt(b"a"), random_gt(b"b"), random_gt(b"c"));
+        
+        assert!(a.mul(&b).mul(&c).equals(&a.mul(&b.mul(&c))));
+        assert!(a.mul(&b).equals(&b.mul(&a)));
+        assert!(a.mul(&GtElement::one()).equals(&a));
+        assert!(!a.mul(&b).equals(&a));
+    }
+    
+    #[test]
+    fn gt_inverse_cancels() {
+        let gt = random_gt(b"inverse");
+        
+        assert!(gt.mul(&gt.inverse()).equals(&GtElement::one()));
+        assert!(gt.inverse().inverse().equals(&gt));
+        assert!(GtElement::one().inverse().equals(&GtElement::one()));
+    }
+    
+    #[test]
+    fn pairing_with_negated_g1_cancels() {
+        let p = G1Point::random_from_seed(b"cancel p");
+        let q = G2Point::random_from_seed(b"cancel q");
+        
+        let product = pairing(&p, &q).mul(&pairing(&p.negate(), &q));
+        assert!(product.equals(&GtElement::one()));
+        assert!(!pairing(&p, &q).equals(&GtElement::one()));
+    }
+    
+    #[test]
+    fn pairing_equal_moves_scalars_across() {
+        let p = G1Point::random_from_seed(b"pairing-equal p");
+        let q = G2Point::random_from_seed(b"pairing-equal q");
+        
+        assert!(pairing_equal(&p.double(), &q, &p, &q.double()));
+        assert!(!pairing_equal(&p.double(), &q, &p, &q));
+        assert!(!pairing_equal(&p, &q, &p.negate(), &q));
+        // Identity pairs contribute 1 on either side
+        assert!(pairing_equal(&G1Point::identity(), &q, &p, &G2Point::identity()));
+        assert!(!pairing_equal(&G1Point::identity(), &q, &p, &q));
+    }
+    
+    #[test]
+    fn gt_pow_matches_repeated_mul() {
+        let gt = random_gt(b"pow");
+        
+        // k = 0 gives one, then each step multiplies in one more factor
+        let mut expected = GtElement::one();
+        for k in 0..8u64 {
+            assert!(gt.pow(&format!("{:064x}", k)).unwrap().equals(&expected));
+            expected = expected.mul(&gt);
+        }
+    }
+    
+    #[test]
+    fn hash_to_g1_rfc9380_vectors() {
+        // RFC 9380 appendix J.9.1, BLS12381G1_XMD:SHA-256_SSWU_RO_ with msg = "" and "abc"
+        let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+        let empty = G1Point::from_coordinates(
+            "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+            "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+        )
+        .unwrap();
+        let abc = G1Point::from_coordinates(
+            "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+            "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+        )
+        .unwrap();
+        
+        assert!(hash_to_g1(b"", dst).equals(&empty));
+        assert!(hash_to_g1(b"abc", dst).equals(&abc));
+    }
+    
+    #[test]
+    fn hash_to_g1_is_deterministic_and_domain_separated() {
+        let point = hash_to_g1(b"message", b"DST-A");
+        
+        assert!(point.is_in_subgroup());
+        assert!(!point.is_infinity());
+        assert!(hash_to_g1(b"message", b"DST-A").equals(&point));
+        assert!(!hash_to_g1(b"messagf", b"DST-A").equals(&point));
+        assert!(!hash_to_g1(b"message", b"DST-B").equals(&point));
+    }
+    
+    #[test]
+    fn encode_to_g1_is_deterministic_and_domain_separated() {
+        let point = encode_to_g1(b"message", b"DST-A");
+        
+        assert!(point.is_in_subgroup());
+        assert!(!point.is_infinity());
+        assert!(encode_to_g1(b"message", b"DST-A").equals(&point));
+        assert!(!encode_to_g1(b"messagf", b"DST-A").equals(&point));
+        assert!(!encode_to_g1(b"message", b"DST-B").equals(&point));
+        // The NU and RO suites are different maps even for the same inputs
+        assert!(!hash_to_g1(b"message", b"DST-A").equals(&point));
+    }
+    
+    #[test]
+    fn verify_eip2537_round_trip() {
+        let sk = bls::SecretKey::from_scalar(Scalar::random_from_seed(b"eip2537 key"));
+        let pk = sk.public_key().to_eip2537_bytes();
+        let sig = sk.sign(b"message", ETH_BLS_DST).to_eip2537_bytes();
+        
+        assert_eq!(verify_eip2537(&pk, b"message", &sig), Ok(true));
+        assert_eq!(verify_eip2537(&pk, b"messagf", &sig), Ok(false));
+        
+        let other = bls::SecretKey::from_scalar(Scalar::random_from_seed(b"eip2537 other"));
+        assert_eq!(verify_eip2537(&other.public_key().to_eip2537_bytes(), b"message", &sig), Ok(false));
+        
+        // A signature under another DST does not verify in the Ethereum ciphersuite
+        let foreign = sk.sign(b"message", b"OTHER_DST").to_eip2537_bytes();
+        assert_eq!(verify_eip2537(&pk, b"message", &foreign), Ok(false));
+        
+        // Flipping a coordinate bit leaves the curve
+        let mut tampered = sig.clone();
+        tampered[255] ^= 1;
+        assert!(verify_eip2537(&pk, b"message", &tampered).is_err());
+        assert_eq!(
+            verify_eip2537(&G1Point::identity().to_eip2537_bytes(), b"message", &sig),
+            Err(CryptoError::PointAtInfinity)
+        );
+    }
+    
+    #[test]
+    #[cfg(not(feature = "failing-rng"))]
+    fn g2_random_points_are_valid() {
+        let points: Vec<G2Point> = (0..100).map(|_| G2Point::random().unwrap()).collect();
+        for p in &points {
+            assert!(!p.is_infinity());
+            assert!(p.is_on_curve());
+            assert!(p.is_in_subgroup());
+        }
+        assert!(!points[0].equals(&points[1]));
+    }
+    
+    #[test]
+    fn g2_generator_coordinates() {
+        // The published BLS12-381 G2 generator
+        let expected = serde_json::json!({
+            "x": {
+                "c0": "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+                "c1": "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"
+            },
+            "y": {
+                "c0": "0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801",
+                "c1": "0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"
+            },
+            "infinity": false
+        });
+        assert_eq!(G2Point::generator().coordinates(), expected);
+    }
+    
+    #[test]
+    fn g2_equals_ignores_representation() {
+        let p = G2Point::random_from_seed(b"g2 equals p");
+        let q = G2Point::random_from_seed(b"g2 equals q");
+        
+        // double() and add() need not agree on projective coordinates, only on the affine point
+        assert!(p.double().equals(&p.add(&p)));
+        assert!(p.equals(&p));
+        assert!(!p.equals(&q));
+        assert!(!p.equals(&p.negate()));
+        assert!(!p.equals(&G2Point::identity()));
+    }
+    
+    #[test]
+    fn g2_generator_encodings() {
+        // ZCash format puts x.c1 before x.c0; 0x80 in the top byte flags compression
+        let compressed = concat!(
+            "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e",
+            "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+        );
+        let serialized = concat!(
+            "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e",
+            "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+            "0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be",
+            "0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801",
+        );
+        
+        assert_eq!(hex::encode(G2Point::generator().compress()), compressed);
+        assert_eq!(hex::encode(G2Point::generator().serialize()), serialized);
+    }
+    
+    #[test]
+    fn g2_decoding_round_trips_and_rejects_corruption() {
+        let p = G2Point::random_from_seed(b"g2 decode");
+        let compressed = p.compress();
+        let serialized = p.serialize();
+        
+        assert!(G2Point::uncompress(&compressed).unwrap().equals(&p));
+        assert!(G2Point::deserialize(&serialized).unwrap().equals(&p));
+        assert!(G2Point::uncompress(&G2Point::identity().compress()).unwrap().is_infinity());
+        assert!(G2Point::deserialize(&G2Point::identity().serialize()).unwrap().is_infinity());
+        
+        assert_eq!(
+            G2Point::uncompress(&compressed[..95]).unwrap_err(),
+            CryptoError::WrongLength { expected: 96, got: 95 }
+        );
+        assert_eq!(
+            G2Point::deserialize(&compressed).unwrap_err(),
+            CryptoError::WrongLength { expected: 192, got: 96 }
+        );
+        
+        // An uncompressed y that no longer satisfies the curve equation
+        let mut corrupted = serialized.clone();
+        corrupted[191] ^= 1;
+        assert_eq!(G2Point::deserialize(&corrupted).unwrap_err(), CryptoError::NotOnCurve);
+        
+        // Compressed bytes without the compression flag
+        let mut unflagged = compressed.clone();
+        unflagged[0] &= 0x7f;
+        assert_eq!(G2Point::uncompress(&unflagged).unwrap_err(), CryptoError::InvalidEncoding);
+        
+        let outside = off_subgroup_g2();
+        assert_eq!(G2Point::uncompress(&outside.compress()).unwrap_err(), CryptoError::NotInSubgroup);
+        assert_eq!(G2Point::deserialize(&outside.serialize()).unwrap_err(), CryptoError::NotInSubgroup);
+    }
+    
+    #[test]
+    fn g2_curve_and_subgroup_checks() {
+        let g = G2Point::generator();
+        for k in [1u64, 2, 7, 1 << 40] {
+            let p = g.scalar_mul_fr(&Scalar::from_u64(k));
+            assert!(p.is_on_curve());
+            assert!(p.is_in_subgroup());
+        }
+        
+        let outside = off_subgroup_g2();
+        assert!(outside.is_on_curve());
+        assert!(!outside.is_in_subgroup());
+        
+        // Swapping in x for y breaks the curve equation
+        let mut malformed = g;
+        malformed.point.y = malformed.point.x;
+        assert!(!malformed.is_on_curve());
+    }
+    
+    #[test]
+    fn negation_flips_y_sort_flag() {
+        // The G1 generator holds the smaller of y and p - y
+        let g = G1Point::generator();
+        assert!(!g.y_is_lexicographically_largest());
+        assert!(g.negate().y_is_lexicographically_largest());
+        
+        for i in 0..8u8 {
+            let p = G1Point::random_from_seed(&[b's', i]);
+            let y = FpElement::new(p.coordinates()["y"].as_str().unwrap()).unwrap();
+            // Equal-length hex compares like the integers it encodes
+            assert_eq!(p.y_is_lexicographically_largest(), y.to_hex() > y.neg().to_hex());
+            assert_ne!(p.y_is_lexicographically_largest(), p.negate().y_is_lexicographically_largest());
+        }
+    }
+    
+    #[test]
+    fn g2_is_infinity() {
+        assert!(G2Point::identity().is_infinity());
+        assert!(!G2Point::generator().is_infinity());
+        
+        let p = G2Point::generator().scalar_mul_fr(&Scalar::random_from_seed(b"g2 infinity"));
+        assert!(!p.is_infinity());
+        assert!(p.subtract(&p).is_infinity());
+        assert!(G2Point::generator().scalar_mul_fr(&Scalar::from_u64(0)).is_infinity());
+    }
+    
+    /// (4, y) is on E1 but outside the prime-order subgroup
+    fn off_subgroup_g1() -> G1Point {
+        G1Point::from_curve_coordinates(
+            &format!("{:0>96}", "4"),
+            "0a989badd40d6212b33cffc3f3763e9bc760f988c9926b26da9dd85e928483446346b8ed00e1de5d5ea93e354abe706c",
+        )
+        .unwrap()
+    }
+    
+    #[test]
+    fn g1_from_coordinates_round_trip_and_rejection() {
+        for p in [G1Point::random_from_seed(b"g1 coordinates"), G1Point::identity()] {
+            let coordinates = p.coordinates();
+            let x = coordinates["x"].as_str().unwrap();
+            let y = coordinates["y"].as_str().unwrap();
+            assert!(G1Point::from_coordinates(x, y).unwrap().equals(&p));
+        }
+        
+        let one = format!("{:0>96}", "1");
+        assert_eq!(G1Point::from_coordinates(&one, &one).unwrap_err(), CryptoError::NotOnCurve);
+        
+        let outside = off_subgroup_g1().coordinates();
+        assert_eq!(
+            G1Point::from_coordinates(outside["x"].as_str().unwrap(), outside["y"].as_str().unwrap()).unwrap_err(),
+            CryptoError::NotInSubgroup
+        );
+    }
+    
+    #[test]
+    fn g2_from_coordinates_round_trip_and_rejection() {
+        let component = |p: &G2Point, axis: &str, c: &str| p.coordinates()[axis][c].as_str().unwrap().to_string();
+        let from = |p: &G2Point| {
+            G2Point::from_coordinates(
+                &component(p, "x", "c0"),
+                &component(p, "x", "c1"),
+                &component(p, "y", "c0"),
+                &component(p, "y", "c1"),
+            )
+        };
+        
+        for p in [G2Point::random_from_seed(b"g2 coordinates"), G2Point::identity()] {
+            assert!(from(&p).unwrap().equals(&p));
+        }
+        assert_eq!(from(&off_subgroup_g2()).unwrap_err(), CryptoError::NotInSubgroup);
+        
+        let one = format!("{:0>96}", "1");
+        assert_eq!(
+            G2Point::from_coordinates(&one, &one, &one, &one).unwrap_err(),
+            CryptoError::NotOnCurve
+        );
+        // An unreduced coordinate is rejected before any curve check
+        let p_hex = "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab";
+        assert!(matches!(
+            G2Point::from_coordinates(p_hex, &one, &one, &one),
+            Err(CryptoError::NonCanonical(_))
+        ));
+    }
+    
+    #[test]
+    fn g1_clear_cofactor_lands_in_subgroup() {
+        // RFC 9380 section 8.8.1: h_eff = 1 - z for G1
+        let h_eff = format!("{:0>64}", "d201000000010001");
+        
+        let outside = off_subgroup_g1();
+        assert!(!outside.is_in_subgroup());
+        
+        let points = [outside, outside.double(), G1Point::random_from_seed(b"in subgroup")];
+        for p in points {
+            let cleared = p.clear_cofactor();
+            assert!(cleared.is_in_subgroup());
+            assert!(!cleared.is_infinity());
+            assert!(cleared.equals(&p.scalar_mul(&h_eff).unwrap()));
+        }
+    }
+    
+    #[test]
+    fn fp2_pow_matches_repeated_mul() {
+        let same = |a: &Fp2Element, b: &Fp2Element| a.c0().equals(&b.c0()) && a.c1().equals(&b.c1());
+        let x = Fp2Element::new(&FpElement::random_from_seed(b"pow c0"), &FpElement::random_from_seed(b"pow c1"));
+        
+        let mut expected = Fp2Element::one();
+        for k in 0..8u8 {
+            assert!(same(&x.pow(&format!("{:02x}", k)).unwrap(), &expected));
+            expected = expected.mul(&x);
+        }
+        
+        // The multiplicative group of Fp2 has order p^2 - 1
+        let order = "02a437a4b8c35fc74bd278eaa22f25e9e2dc90e50e7046b466e59e49349e8bd050a62cfd16ddca6ef53149330978ef01\
+                     1d68619c86185c7b292e85a87091a04966bf91ed3e71b743162c338362113cfd7ced6b1d76382eab26aa00001c718e38";
+        assert!(same(&x.pow(order).unwrap(), &Fp2Element::one()));
+    }
+    
+    #[test]
+    fn sqrt_both_returns_both_roots() {
+        let x = FpElement::random_from_seed(b"sqrt both");
+        let square = x.mul(&x);
+        
+        let (a, b) = square.sqrt_both().unwrap();
+        assert!(a.mul(&a).equals(&square));
+        assert!(b.mul(&b).equals(&square));
+        assert!(a.add(&b).is_zero());
+        assert!(!a.equals(&b));
+        assert!(a.equals(&x) || b.equals(&x));
+        
+        let (z0, z1) = fp(0).sqrt_both().unwrap();
+        assert!(z0.is_zero() && z1.is_zero());
+        
+        // -1 and -x² are non-residues since p ≡ 3 (mod 4)
+        assert!(fp(1).neg().sqrt_both().is_none());
+        assert!(square.neg().sqrt_both().is_none());
+    }
+    
+    #[test]
+    fn to_hex_into_matches_to_hex() {
+        let x = FpElement::random_from_seed(b"to hex into");
+        
+        let mut buf = [0u8; 96];
+        x.to_hex_into(&mut buf).unwrap();
+        assert_eq!(std::str::from_utf8(&buf).unwrap(), x.to_hex());
+        
+        for len in [0, 95, 97] {
+            assert_eq!(
+                x.to_hex_into(&mut vec![0u8; len]).unwrap_err(),
+                CryptoError::WrongLength { expected: 96, got: len }
+            );
+        }
+    }
+    
+    #[test]
+    fn is_zero_sees_every_byte() {
+        assert!(fp(0).is_zero());
+        assert!(fp(5).add(&fp(5).neg()).is_zero());
+        
+        // A single set bit in the first, a middle and the last byte of the encoding
+        for bit in [0, 191, 380] {
+            let mut bytes = [0u8; 48];
+            bytes[47 - bit / 8] = 1 << (bit % 8);
+            let x = FpElement::from_bytes(&bytes).unwrap();
+            assert!(!x.is_zero());
+            assert!(!x.equals(&fp(0)));
+        }
+    }
+    
+    #[test]
+    fn montgomery_bytes_round_trip() {
+        let x = FpElement::random_from_seed(b"montgomery");
+        let raw = x.to_montgomery_bytes();
+        
+        assert_eq!(raw.len(), 48);
+        assert!(FpElement::from_montgomery_bytes(&raw).unwrap().equals(&x));
+        // The residue is x·R, not the canonical encoding in either byte order
+        let canonical = hex::decode(x.to_hex()).unwrap();
+        assert_ne!(raw, canonical);
+        assert_ne!(raw.iter().rev().copied().collect::<Vec<u8>>(), canonical);
+        
+        assert_eq!(
+            FpElement::from_montgomery_bytes(&raw[..47]).unwrap_err(),
+            CryptoError::WrongLength { expected: 48, got: 47 }
+        );
+        assert!(matches!(
+            FpElement::from_montgomery_bytes(&[0xff; 48]),
+            Err(CryptoError::NonCanonical(_))
+        ));
+    }
+    
+    #[test]
+    fn random_from_seed_is_reproducible() {
+        assert!(FpElement::random_from_seed(b"seed").equals(&FpElement::random_from_seed(b"seed")));
+        assert!(!FpElement::random_from_seed(b"seed").equals(&FpElement::random_from_seed(b"seee")));
+        
+        assert!(Scalar::random_from_seed(b"seed").equals(&Scalar::random_from_seed(b"seed")));
+        assert!(!Scalar::random_from_seed(b"seed").equals(&Scalar::random_from_seed(b"seee")));
+        
+        assert!(G1Point::random_from_seed(b"seed").equals(&G1Point::random_from_seed(b"seed")));
+        assert!(!G1Point::random_from_seed(b"seed").equals(&G1Point::random_from_seed(b"seee")));
+        
+        assert!(G2Point::random_from_seed(b"seed").equals(&G2Point::random_from_seed(b"seed")));
+        assert!(!G2Point::random_from_seed(b"seed").equals(&G2Point::random_from_seed(b"seee")));
+    }
+    
+    #[test]
+    fn g1_batch_coordinates_match_per_point() {
+        // Sums and doublings leave Z != 1, so the batch inversion has real work to do
+        let mut points: Vec<G1Point> = (0..8u8)
+            .map(|i| G1Point::random_from_seed(&[b'b', i]).add(&G1Point::generator().double()))
+            .collect();
+        points.insert(3, G1Point::identity());
+        
+        let batch = g1_batch_coordinates(&points);
+        assert_eq!(batch.len(), points.len());
+        for (coordinates, p) in batch.iter().zip(&points) {
+            assert_eq!(coordinates, &p.coordinates());
+        }
+        assert!(g1_batch_coordinates(&[]).is_empty());
+    }
+    
+    #[test]
+    fn point_buffer_reads_back_points() {
+        let g1s: Vec<G1Point> = (0..5u8).map(|i| G1Point::random_from_seed(&[b'g', 1, i])).collect();
+        let buf = PointBuffer::from_g1(g1s.clone());
+        assert_eq!((buf.count(), buf.point_size(), buf.len()), (5, 48, 240));
+        assert_eq!(buf.offset(), buf.as_bytes().as_ptr() as usize);
+        for (chunk, p) in buf.as_bytes().chunks_exact(48).zip(&g1s) {
+            assert!(G1Point::uncompress(chunk).unwrap().equals(p));
+        }
+        
+        let g2s: Vec<G2Point> = (0..3u8).map(|i| G2Point::random_from_seed(&[b'g', 2, i])).collect();
+        let buf = PointBuffer::from_g2(g2s.clone());
+        assert_eq!((buf.count(), buf.point_size(), buf.len()), (3, 96, 288));
+        for (chunk, p) in buf.as_bytes().chunks_exact(96).zip(&g2s) {
+            assert!(G2Point::uncompress(chunk).unwrap().equals(p));
+        }
+        
+        assert!(PointBuffer::from_g1(vec![]).is_empty());
+    }
+    
+    /// Scalars that stress digit recoding: zero, one, r - 1, and every nibble at the carry boundary
+    fn edge_scalars() -> Vec<Scalar> {
+        let mut scalars = vec![
+            Scalar::from_u64(0),
+            Scalar::from_u64(1),
+            Scalar::from_u64(0).sub(&Scalar::from_u64(1)),
+            Scalar::new(&format!("7{}", "8".repeat(63))).unwrap(),
+            Scalar::new(&format!("7{}", "f".repeat(63))).unwrap(),
+        ];
+        scalars.extend((0..8u8).map(|i| Scalar::random_from_seed(&[b'e', i])));
+        scalars
+    }
+    
+    #[test]
+    fn g1_precomputed_matches_scalar_mul() {
+        let p = G1Point::random_from_seed(b"precomputed");
+        let table = G1Precomputed::new(&p);
+        for s in edge_scalars() {
+            assert!(table.scalar_mul(&s).equals(&p.scalar_mul_fr(&s)));
+        }
+        
+        let identity = G1Precomputed::new(&G1Point::identity());
+        assert!(identity.scalar_mul(&Scalar::random_from_seed(b"any")).is_infinity());
+    }
+    
+    #[test]
+    fn generator_mul_matches_scalar_mul() {
+        for s in edge_scalars() {
+            assert!(g1_mul_generator(&s).equals(&G1Point::generator().scalar_mul_fr(&s)));
+            assert!(g2_mul_generator(&s).equals(&G2Point::generator().scalar_mul_fr(&s)));
+        }
+    }
+    
+    #[test]
+    fn g1_msm_windowed_matches_g1_msm() {
+        let (points, scalars) = msm_inputs(24, 4);
+        let expected = g1_msm(points.clone(), scalars.clone()).unwrap();
+        for window in MSM_MIN_WINDOW_BITS..=MSM_MAX_WINDOW_BITS {
+            let result = g1_msm_windowed(points.clone(), scalars.clone(), window).unwrap();
+            assert!(result.equals(&expected), "window {}", window);
+        }
+        
+        assert!(g1_msm_windowed(vec![], vec![], 8).unwrap().is_infinity());
+        assert!(g1_msm_windowed(points[..1].to_vec(), scalars[..1].to_vec(), 8)
+            .unwrap()
+            .equals(&points[0].scalar_mul_fr(&scalars[0])));
+        
+        for window in [0, MSM_MIN_WINDOW_BITS - 1, MSM_MAX_WINDOW_BITS + 1] {
+            assert!(matches!(
+                g1_msm_windowed(points.clone(), scalars.clone(), window),
+                Err(CryptoError::InvalidParameter(_))
+            ));
+        }
+        assert_eq!(
+            g1_msm_windowed(points, scalars[..2].to_vec(), 8).unwrap_err(),
+            CryptoError::LengthMismatch { left: 24, right: 2 }
+        );
+    }
+    
+    #[test]
+    fn inner_product_matches_naive_sum() {
+        let (points, scalars) = msm_inputs(6, 5);
+        let naive = points
+            .iter()
+            .zip(&scalars)
+            .fold(G1Point::identity(), |acc, (p, s)| acc.add(&p.scalar_mul_fr(s)));
+        assert!(inner_product(scalars.clone(), points.clone()).unwrap().equals(&naive));
+        
+        assert_eq!(
+            inner_product(scalars[..4].to_vec(), points).unwrap_err(),
+            CryptoError::LengthMismatch { left: 6, right: 4 }
+        );
+    }
+    
+    #[test]
+    fn g1_sum_matches_folded_add() {
+        let points: Vec<G1Point> = (0..3u8).map(|i| G1Point::random_from_seed(&[b'+', i])).collect();
+        let (p, q, r) = (points[0], points[1], points[2]);
+        assert!(g1_sum(points).equals(&p.add(&q).add(&r)));
+        
+        // Repeated and cancelling terms go through doubling and infinity
+        assert!(g1_sum(vec![p, p]).equals(&p.double()));
+        assert!(g1_sum(vec![p, p.negate(), q]).equals(&q));
+        assert!(g1_sum(vec![]).is_infinity());
+    }
+    
+    #[test]
+    fn fp_and_scalar_sums_match_folded_add() {
+        let elements: Vec<FpElement> = (0..5u8).map(|i| FpElement::random_from_seed(&[b'+', i])).collect();
+        let folded = elements.iter().fold(fp(0), |acc, e| acc.add(e));
+        assert!(fp_sum(elements).equals(&folded));
+        assert!(fp_sum(vec![]).is_zero());
+        
+        let scalars: Vec<Scalar> = (0..5u8).map(|i| Scalar::random_from_seed(&[b'+', i])).collect();
+        let folded = scalars.iter().fold(Scalar::from_u64(0), |acc, s| acc.add(s));
+        assert!(scalar_sum(scalars).equals(&folded));
+        assert!(scalar_sum(vec![]).is_zero());
+        // Wraps modulo r
+        let minus_one = Scalar::from_u64(0).sub(&Scalar::from_u64(1));
+        assert!(scalar_sum(vec![minus_one, Scalar::from_u64(3)]).equals(&Scalar::from_u64(2)));
+    }
+    
+    #[test]
+    fn streaming_hasher_matches_one_shot() {
+        let data: Vec<u8> = (0..1000u32).map(|i| (i * 31 % 251) as u8).collect();
+        let one_shot = [
+            HashFunctions::sha256(&data),
+            HashFunctions::sha512(&data),
+            HashFunctions::blake2b(&data),
+            HashFunctions::sha3_256(&data),
+            HashFunctions::keccak256(&data),
+            HashFunctions::blake3(&data),
+        ];
+        
+        // The one-shot algorithm tags double as the StreamingHasher names
+        for expected in one_shot {
+            let mut hasher = StreamingHasher::new(&expected.algorithm).unwrap();
+            // Uneven chunks, including an empty one, straddle every block size in use
+            let mut rest = &data[..];
+            for len in [0, 1, 63, 64, 65, 127, 136, 200].into_iter().cycle() {
+                let (chunk, tail) = rest.split_at(len.min(rest.len()));
+                hasher.update(chunk);
+                rest = tail;
+                if rest.is_empty() {
+                    break;
+                }
+            }
+            let streamed = hasher.finalize();
+            assert_eq!(streamed.hash, expected.hash, "{}", expected.algorithm);
+            assert_eq!(streamed.algorithm, expected.algorithm);
+        }
+        
+        assert!(matches!(StreamingHasher::new("MD5"), Err(CryptoError::InvalidParameter(_))));
+    }
+    
+    #[test]
+    fn blake2b_sized_vectors_and_bounds() {
+        // RFC 7693 BLAKE2b with a 32-byte digest, for the empty message and "abc"
+        let empty = HashFunctions::blake2b_sized(b"", 32).unwrap();
+        assert_eq!(HashFunctions::to_hex(&empty), "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8");
+        assert_eq!(empty.algorithm, "BLAKE2b-256");
+        assert_eq!(
+            HashFunctions::to_hex(&HashFunctions::blake2b_sized(b"abc", 32).unwrap()),
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+        );
+        
+        // The full 64-byte output is plain BLAKE2b-512
+        assert_eq!(HashFunctions::blake2b_sized(b"abc", 64).unwrap().hash, HashFunctions::blake2b(b"abc").hash);
+        assert_eq!(HashFunctions::blake2b_sized(b"abc", 1).unwrap().hash.len(), 1);
+        
+        for out_len in [0, 65] {
+            assert!(matches!(
+                HashFunctions::blake2b_sized(b"abc", out_len),
+                Err(CryptoError::InvalidParameter(_))
+            ));
+        }
+    }
+    
+    #[test]
+    fn shake256_vectors_and_prefixes() {
+        // FIPS 202 SHAKE256 of the empty message, 64 bytes of output
+        let empty = HashFunctions::shake256(b"", 64);
+        assert_eq!(
+            HashFunctions::to_hex(&empty),
+            "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f\
+             d75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be"
+        );
+        assert_eq!(empty.algorithm, "SHAKE256");
+        
+        // Any output length is a prefix of a longer one, across the 136-byte rate
+        let long = HashFunctions::shake256(b"abc", 300).hash;
+        for out_len in [0, 1, 32, 136, 137, 299] {
+            assert_eq!(HashFunctions::shake256(b"abc", out_len).hash, long[..out_len]);
+        }
+        assert_ne!(HashFunctions::shake256(b"abd", 32).hash, long[..32]);
+    }
+    
+    #[test]
+    fn batch_hashing_matches_single_calls() {
+        let inputs: [&[u8]; 4] = [b"", b"a", b"abc", &[0xffu8; 200]];
+        let hashes: [fn(&[u8]) -> HashResult; 6] = [
+            HashFunctions::sha256,
+            HashFunctions::sha512,
+            HashFunctions::blake2b,
+            HashFunctions::sha3_256,
+            HashFunctions::keccak256,
+            HashFunctions::blake3,
+        ];
+        
+        for hash in hashes {
+            let batch = HashFunctions::batch(&inputs, hash);
+            assert_eq!(batch.len(), inputs.len());
+            for (result, input) in batch.iter().zip(inputs) {
+                assert_eq!(result.hash, hash(input).hash);
+                assert_eq!(result.algorithm, hash(input).algorithm);
+            }
+        }
+        assert!(HashFunctions::batch::<Vec<u8>>(&[], HashFunctions::sha256).is_empty());
+    }
+    
+    #[test]
+    fn hash_chain_round_trip_and_tamper() {
+        let chain = build_hash_chain(b"seed", 5);
+        assert_eq!(chain.len(), 5);
+        assert_eq!(chain[0].hash, HashFunctions::sha256(b"seed").hash);
+        for (i, link) in chain.iter().enumerate().skip(1) {
+            assert_eq!(link.hash, HashFunctions::sha256(&chain[i - 1].hash).hash);
+        }
+        for (i, link) in chain.iter().enumerate() {
+            assert!(verify_hash_chain(b"seed", i + 1, link));
+        }
+        
+        let last = &chain[4];
+        assert!(!verify_hash_chain(b"seee", 5, last));
+        assert!(!verify_hash_chain(b"seed", 4, last));
+        assert!(!verify_hash_chain(b"seed", 6, last));
+        
+        let mut tampered = last.clone();
+        tampered.hash[31] ^= 1;
+        assert!(!verify_hash_chain(b"seed", 5, &tampered));
+        let mut retagged = last.clone();
+        retagged.algorithm = "SHA3-256".to_string();
+        assert!(!verify_hash_chain(b"seed", 5, &retagged));
+        
+        assert!(build_hash_chain(b"seed", 0).is_empty());
+    }
+    
+    #[test]
+    fn json_round_trip() {
+        let p = G1Point::random_from_seed(b"json");
+        for point in [p, G1Point::identity()] {
+            let text = serde_json::to_string(&point.to_json_value()).unwrap();
+            let parsed: serde_json::Value = serde_json::from_str(&text).unwrap();
+            assert!(G1Point::from_json_value(&parsed).unwrap().equals(&point));
+            // get_coordinates output has no compressed field and is accepted as well
+            assert!(G1Point::from_json_value(&point.coordinates()).unwrap().equals(&point));
+        }
+        
+        let mut wrong_flag = p.to_json_value();
+        wrong_flag["infinity"] = true.into();
+        assert_eq!(G1Point::from_json_value(&wrong_flag).unwrap_err(), CryptoError::InvalidEncoding);
+        
+        let mut wrong_compressed = p.to_json_value();
+        wrong_compressed["compressed"] = hex::encode(p.negate().compress()).into();
+        assert_eq!(G1Point::from_json_value(&wrong_compressed).unwrap_err(), CryptoError::InvalidEncoding);
+        
+        let mut missing = p.to_json_value();
+        missing.as_object_mut().unwrap().remove("y");
+        assert!(matches!(G1Point::from_json_value(&missing), Err(CryptoError::Serialization(_))));
+        
+        assert_eq!(
+            G1Point::from_json_value(&off_subgroup_g1().coordinates()).unwrap_err(),
+            CryptoError::NotInSubgroup
+        );
+    }
+    
+    #[test]
+    fn self_test_report_passes_every_check() {
+        let report = CryptoModule::self_test_report();
+        assert_eq!(report["status"], "success");
+        
+        let operations = report["operations"].as_object().unwrap();
+        assert_eq!(operations.len(), self_test::CHECKS.len());
+        for (name, _) in self_test::CHECKS {
+            assert_eq!(operations[*name], true, "{}", name);
+        }
+    }
+    
+    #[test]
+    #[cfg(not(feature = "failing-rng"))]
+    fn measure_reports_one_entry_per_operation() {
+        let metrics = Benchmarks::measure(100).unwrap();
+        let operations: Vec<String> = metrics.iter().map(|m| m.operation()).collect();
+        assert_eq!(
+            operations,
+            ["Field Multiplication", "G1 Addition", "G1 Scalar Multiplication", "Pairing", "SHA-256"]
+        );
+        
+        let iterations: Vec<u32> = metrics.iter().map(|m| m.iterations).collect();
+        assert_eq!(iterations, [100, 100, 10, 1, 100]);
+        for m in &metrics {
+            assert!(m.wasm_time_us > 0, "{}", m.operation);
+        }
+    }
+    
+    #[test]
+    fn time_operation_grows_with_iterations() {
+        let sleep = || std::thread::sleep(std::time::Duration::from_millis(1));
+        let short = time_operation("sleep", 2, sleep);
+        let long = time_operation("sleep", 10, sleep);
+        
+        assert_eq!((short.operation.as_str(), short.iterations), ("sleep", 2));
+        assert!(short.wasm_time_us >= 2_000);
+        assert!(long.wasm_time_us >= 10_000);
+        assert!(long.wasm_time_us > short.wasm_time_us);
+    }
+    
+    #[test]
+    fn criterion_json_parses_with_operation_names() {
+        let metrics = [
+            time_operation("Field Multiplication", 3, || {}),
+            PerformanceMetrics { operation: "Pairing".to_string(), iterations: 1, wasm_time_us: 1500, memory_used_kb: 0 },
+        ];
+        let json: serde_json::Value = serde_json::from_str(&Benchmarks::criterion_json(&metrics).unwrap()).unwrap();
+        let records = json.as_array().unwrap();
+        
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["id"], "wasm/Field Multiplication");
+        assert_eq!(records[1]["id"], "wasm/Pairing");
+        for record in records {
+            assert_eq!(record["reason"], "benchmark-complete");
+        }
+        assert_eq!(records[1]["mean"]["estimate"], 1_500_000.0);
+        assert_eq!(records[1]["mean"]["unit"], "ns");
+    }
+    
+    #[test]
+    fn memory_usage_report_is_consistent() {
+        let report = Benchmarks::memory_usage_report();
+        let bytes = report["memory_bytes"].as_u64().unwrap();
+        assert_eq!(report["memory_pages"].as_u64().unwrap(), bytes / WASM_PAGE_BYTES);
+        assert_eq!(report["heap_total_kb"].as_u64().unwrap(), bytes / 1024);
+        #[cfg(not(target_arch = "wasm32"))]
+        assert_eq!(bytes, 0);
+    }
+    
+    #[cfg(target_arch = "wasm32")]
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    fn memory_usage_grows_with_allocation() {
+        let before = Benchmarks::memory_usage_report()["memory_bytes"].as_u64().unwrap();
+        // Larger than any free space the allocator could already hold, so memory must grow
+        let block = vec![1u8; 4 * before as usize];
+        let after = Benchmarks::memory_usage_report()["memory_bytes"].as_u64().unwrap();
+        
+        assert!(after > before);
+        assert_eq!(after % WASM_PAGE_BYTES, 0);
+        std::hint::black_box(block);
     }
 }
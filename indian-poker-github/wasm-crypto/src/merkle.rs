@@ -0,0 +1,187 @@
+use sha2::{Digest, Sha256};
+use wasm_bindgen::prelude::*;
+
+use crate::CryptoError;
+
+// RFC 6962 domain separation between leaf and interior hashes
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Binary SHA-256 Merkle tree over a list of byte-string leaves
+///
+/// A node without a sibling is promoted to the next level unchanged, so no leaf
+/// is ever duplicated.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+/// Inclusion proof: sibling hashes from leaf to root with the side each sits on
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct MerkleProof {
+    leaf_index: usize,
+    siblings: Vec<[u8; 32]>,
+    sibling_is_left: Vec<bool>,
+}
+
+#[wasm_bindgen]
+impl MerkleTree {
+    /// Build a tree over the given leaves; at least one leaf is required
+    #[wasm_bindgen(constructor)]
+    pub fn new(leaves: Vec<js_sys::Uint8Array>) -> Result<MerkleTree, CryptoError> {
+        let leaves: Vec<Vec<u8>> = leaves.iter().map(|leaf| leaf.to_vec()).collect();
+        MerkleTree::from_leaves(&leaves)
+    }
+    
+    /// 32-byte root hash
+    #[wasm_bindgen]
+    pub fn root(&self) -> Vec<u8> {
+        self.levels[self.levels.len() - 1][0].to_vec()
+    }
+    
+    /// Number of leaves
+    #[wasm_bindgen]
+    pub fn leaf_count(&self) -> usize {
+        self.levels[0].len()
+    }
+    
+    /// Inclusion proof for the leaf at `index`
+    #[wasm_bindgen]
+    pub fn prove(&self, index: usize) -> Result<MerkleProof, CryptoError> {
+        if index >= self.leaf_count() {
+            return Err(CryptoError::InvalidParameter(format!(
+                "leaf index {} out of range for {} leaves",
+                index,
+                self.leaf_count()
+            )));
+        }
+        
+        let mut siblings = Vec::new();
+        let mut sibling_is_left = Vec::new();
+        let mut position = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = position ^ 1;
+            if sibling < level.len() {
+                siblings.push(level[sibling]);
+                sibling_is_left.push(sibling < position);
+            }
+            position /= 2;
+        }
+        
+        Ok(MerkleProof {
+            leaf_index: index,
+            siblings,
+            sibling_is_left,
+        })
+    }
+}
+
+impl MerkleTree {
+    /// Rust-side constructor taking the leaves as byte slices
+    pub fn from_leaves<L: AsRef<[u8]>>(leaves: &[L]) -> Result<MerkleTree, CryptoError> {
+        if leaves.is_empty() {
+            return Err(CryptoError::EmptyInput);
+        }
+        
+        let mut levels = vec![leaves.iter().map(|leaf| hash_leaf(leaf.as_ref())).collect::<Vec<_>>()];
+        while levels[levels.len() - 1].len() > 1 {
+            let next = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_node(left, right),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        Ok(MerkleTree { levels })
+    }
+}
+
+#[wasm_bindgen]
+impl MerkleProof {
+    /// Index of the proven leaf
+    #[wasm_bindgen(getter)]
+    pub fn leaf_index(&self) -> usize {
+        self.leaf_index
+    }
+    
+    /// Sibling hashes from leaf to root, concatenated (32 bytes each)
+    #[wasm_bindgen(getter)]
+    pub fn siblings(&self) -> Vec<u8> {
+        self.siblings.concat()
+    }
+    
+    /// For each sibling, 1 when it sits to the left of the running hash
+    #[wasm_bindgen(getter)]
+    pub fn directions(&self) -> Vec<u8> {
+        self.sibling_is_left.iter().map(|&left| left as u8).collect()
+    }
+}
+
+/// Check that `leaf` is included under `root` according to `proof`
+#[wasm_bindgen]
+pub fn merkle_verify(root: &[u8], leaf: &[u8], proof: &MerkleProof) -> bool {
+    let mut current = hash_leaf(leaf);
+    for (sibling, &is_left) in proof.siblings.iter().zip(&proof.sibling_is_left) {
+        current = match is_left {
+            true => hash_node(sibling, &current),
+            false => hash_node(&current, sibling),
+        };
+    }
+    current.as_slice() == root
+}
+
+fn hash_leaf(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_PREFIX]);
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn leaves(n: usize) -> Vec<Vec<u8>> {
+        (0..n).map(|i| format!("leaf {}", i).into_bytes()).collect()
+    }
+    
+    #[test]
+    fn every_leaf_proves_with_odd_count() {
+        let leaves = leaves(7);
+        let tree = MerkleTree::from_leaves(&leaves).unwrap();
+        for (i, leaf) in leaves.iter().enumerate() {
+            let proof = tree.prove(i).unwrap();
+            assert_eq!(proof.leaf_index(), i);
+            assert!(merkle_verify(&tree.root(), leaf, &proof));
+        }
+    }
+    
+    #[test]
+    fn wrong_leaf_fails() {
+        let leaves = leaves(5);
+        let tree = MerkleTree::from_leaves(&leaves).unwrap();
+        let proof = tree.prove(2).unwrap();
+        assert!(!merkle_verify(&tree.root(), &leaves[3], &proof));
+        assert!(!merkle_verify(&tree.root(), b"not a leaf", &proof));
+    }
+    
+    #[test]
+    fn out_of_range_index_is_an_error() {
+        let tree = MerkleTree::from_leaves(&leaves(5)).unwrap();
+        assert!(matches!(tree.prove(5), Err(CryptoError::InvalidParameter(_))));
+        assert_eq!(MerkleTree::from_leaves::<&[u8]>(&[]).unwrap_err(), CryptoError::EmptyInput);
+    }
+}
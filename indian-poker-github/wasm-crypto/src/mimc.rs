@@ -0,0 +1,92 @@
+use sha3::{Digest, Keccak256};
+use wasm_bindgen::prelude::*;
+
+use crate::{CryptoError, Scalar};
+
+/// Recommended round count: 2·log_5(r) for the x^5 Feistel network over a 255-bit field
+pub const MIMC_ROUNDS: usize = 220;
+
+/// Seed of the round-constant chain
+const MIMC_SEED: &[u8] = b"INDIAN-POKER-MIMC-FEISTEL-BLS12381";
+
+/// Hash field elements with a MiMC-Feistel sponge over Fr (x^5 round function, zero key)
+///
+/// Each input is added to the left half of the state before one pass of the Feistel
+/// network; the output is the final left half (zero for no inputs). Round constants are a
+/// Keccak-256 chain from a fixed seed, with the first and last set to zero. Use `MIMC_ROUNDS`
+/// unless you have a reason not to.
+#[wasm_bindgen]
+pub fn mimc_hash(inputs: Vec<Scalar>, rounds: usize) -> Result<Scalar, CryptoError> {
+    if rounds == 0 {
+        return Err(CryptoError::InvalidParameter("MiMC needs at least one round".to_string()));
+    }
+    
+    let constants = round_constants(rounds);
+    let mut left = Scalar::from_u64(0);
+    let mut right = Scalar::from_u64(0);
+    for input in &inputs {
+        left = left.add(input);
+        (left, right) = feistel(left, right, &constants);
+    }
+    
+    Ok(left)
+}
+
+/// One pass of the Feistel network; the last round skips the swap
+fn feistel(mut left: Scalar, mut right: Scalar, constants: &[Scalar]) -> (Scalar, Scalar) {
+    for (i, c) in constants.iter().enumerate() {
+        let t = left.add(c);
+        let t2 = t.mul(&t);
+        let t5 = t2.mul(&t2).mul(&t);
+        if i + 1 < constants.len() {
+            (left, right) = (right.add(&t5), left);
+        } else {
+            right = right.add(&t5);
+        }
+    }
+    (left, right)
+}
+
+fn round_constants(rounds: usize) -> Vec<Scalar> {
+    let mut constants = Vec::with_capacity(rounds);
+    let mut digest = Keccak256::digest(MIMC_SEED);
+    constants.push(Scalar::from_u64(0));
+    for _ in 1..rounds.saturating_sub(1) {
+        digest = Keccak256::digest(digest);
+        constants.push(Scalar::from_wide_be(&digest));
+    }
+    if rounds > 1 {
+        constants.push(Scalar::from_u64(0));
+    }
+    constants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn inputs() -> Vec<Scalar> {
+        (1..=4).map(Scalar::from_u64).collect()
+    }
+    
+    #[test]
+    fn hash_is_deterministic() {
+        let first = mimc_hash(inputs(), MIMC_ROUNDS).unwrap();
+        assert!(first.equals(&mimc_hash(inputs(), MIMC_ROUNDS).unwrap()));
+    }
+    
+    #[test]
+    fn changing_one_input_changes_the_output() {
+        let base = mimc_hash(inputs(), MIMC_ROUNDS).unwrap();
+        for i in 0..4 {
+            let mut changed = inputs();
+            changed[i] = changed[i].add(&Scalar::from_u64(1));
+            assert!(!mimc_hash(changed, MIMC_ROUNDS).unwrap().equals(&base));
+        }
+    }
+    
+    #[test]
+    fn zero_rounds_is_rejected() {
+        assert!(matches!(mimc_hash(inputs(), 0), Err(CryptoError::InvalidParameter(_))));
+    }
+}
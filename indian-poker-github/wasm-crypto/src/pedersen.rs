@@ -0,0 +1,97 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{hash_to_g1, CryptoError, G1Point, Scalar};
+
+/// Domain separation tag used to derive the second Pedersen generator
+const PEDERSEN_H_DST: &[u8] = b"INDIAN-POKER-PEDERSEN-H_BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+/// Second generator H for Pedersen commitments
+///
+/// Derived by hashing to G1, so nobody knows its discrete log with respect to `G1Point::generator()`.
+#[wasm_bindgen]
+pub fn pedersen_generator_h() -> G1Point {
+    hash_to_g1(b"H", PEDERSEN_H_DST)
+}
+
+/// Pedersen commitment `value * G + blinding * H`
+#[wasm_bindgen]
+pub fn pedersen_commit(value: &Scalar, blinding: &Scalar) -> G1Point {
+    G1Point::generator()
+        .scalar_mul_fr(value)
+        .add(&pedersen_generator_h().scalar_mul_fr(blinding))
+}
+
+/// Check that `(value, blinding)` opens `commitment`
+#[wasm_bindgen]
+pub fn pedersen_verify(commitment: &G1Point, value: &Scalar, blinding: &Scalar) -> bool {
+    pedersen_commit(value, blinding).equals(commitment)
+}
+
+/// Re-commit a revealed value under a fresh blinding, after checking the old opening
+///
+/// The new commitment opens to the same value with `new_blinding` but is unlinkable to
+/// the old one without knowing both blindings.
+#[wasm_bindgen]
+pub fn recommit(
+    value: &Scalar,
+    old_blinding: &Scalar,
+    old_commitment: &G1Point,
+    new_blinding: &Scalar,
+) -> Result<G1Point, CryptoError> {
+    if !pedersen_verify(old_commitment, value, old_blinding) {
+        return Err(CryptoError::InvalidParameter(
+            "old blinding does not open the old commitment to this value".to_string(),
+        ));
+    }
+    Ok(pedersen_commit(value, new_blinding))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn recommit_refreshes_the_blinding() {
+        let value = Scalar::from_u64(42);
+        let old_blinding = Scalar::random_from_seed(b"old blinding");
+        let new_blinding = Scalar::random_from_seed(b"new blinding");
+        let old = pedersen_commit(&value, &old_blinding);
+        
+        let new = recommit(&value, &old_blinding, &old, &new_blinding).unwrap();
+        assert!(pedersen_verify(&new, &value, &new_blinding));
+        assert!(!new.equals(&old));
+    }
+    
+    #[test]
+    fn recommit_rejects_a_wrong_opening() {
+        let value = Scalar::from_u64(42);
+        let old_blinding = Scalar::random_from_seed(b"old blinding");
+        let new_blinding = Scalar::random_from_seed(b"new blinding");
+        let old = pedersen_commit(&value, &old_blinding);
+        
+        let wrong_blinding = old_blinding.add(&Scalar::from_u64(1));
+        assert!(matches!(
+            recommit(&value, &wrong_blinding, &old, &new_blinding),
+            Err(CryptoError::InvalidParameter(_))
+        ));
+        assert!(recommit(&Scalar::from_u64(43), &old_blinding, &old, &new_blinding).is_err());
+    }
+    
+    #[test]
+    fn commitments_are_additively_homomorphic() {
+        let (v1, r1) = (Scalar::from_u64(3), Scalar::random_from_seed(b"r1"));
+        let (v2, r2) = (Scalar::from_u64(9), Scalar::random_from_seed(b"r2"));
+        let sum = pedersen_commit(&v1, &r1).add(&pedersen_commit(&v2, &r2));
+        assert!(sum.equals(&pedersen_commit(&v1.add(&v2), &r1.add(&r2))));
+    }
+    
+    #[test]
+    fn verify_checks_value_and_blinding() {
+        let value = Scalar::from_u64(42);
+        let blinding = Scalar::random_from_seed(b"blinding");
+        let commitment = pedersen_commit(&value, &blinding);
+        assert!(pedersen_verify(&commitment, &value, &blinding));
+        assert!(!pedersen_verify(&commitment, &Scalar::from_u64(43), &blinding));
+        assert!(!pedersen_verify(&commitment, &value, &blinding.add(&Scalar::from_u64(1))));
+    }
+}
@@ -0,0 +1,103 @@
+use wasm_bindgen::prelude::*;
+
+use crate::transcript::Transcript;
+use crate::{CryptoError, G1Point, Scalar};
+
+/// Domain separation tag for the Schnorr challenge
+const SCHNORR_DST: &[u8] = b"INDIAN-POKER-SCHNORR-DLOG-V1";
+
+/// Non-interactive Schnorr proof of knowledge of `sk` with `pk = sk * G`
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct SchnorrProof {
+    commitment: G1Point,
+    response: Scalar,
+}
+
+#[wasm_bindgen]
+impl SchnorrProof {
+    #[wasm_bindgen(constructor)]
+    pub fn new(commitment: &G1Point, response: &Scalar) -> SchnorrProof {
+        SchnorrProof {
+            commitment: *commitment,
+            response: response.clone(),
+        }
+    }
+    
+    /// Nonce commitment `k * G`
+    #[wasm_bindgen(getter)]
+    pub fn commitment(&self) -> G1Point {
+        self.commitment
+    }
+    
+    /// Response `k + e * sk`
+    #[wasm_bindgen(getter)]
+    pub fn response(&self) -> Scalar {
+        self.response.clone()
+    }
+}
+
+/// Prove knowledge of the secret key behind `pk`, bound to the context `ctx`
+#[wasm_bindgen]
+pub fn schnorr_prove(sk: &Scalar, pk: &G1Point, ctx: &[u8]) -> Result<SchnorrProof, CryptoError> {
+    let nonce = Scalar::random()?;
+    let commitment = G1Point::generator().scalar_mul_fr(&nonce);
+    let challenge = schnorr_challenge(pk, &commitment, ctx);
+    
+    Ok(SchnorrProof {
+        commitment,
+        response: nonce.add(&challenge.mul(sk)),
+    })
+}
+
+/// Verify a Schnorr proof for `pk` under the same context it was produced with
+#[wasm_bindgen]
+pub fn schnorr_verify(pk: &G1Point, proof: &SchnorrProof, ctx: &[u8]) -> bool {
+    let challenge = schnorr_challenge(pk, &proof.commitment, ctx);
+    let lhs = G1Point::generator().scalar_mul_fr(&proof.response);
+    let rhs = proof.commitment.add(&pk.scalar_mul_fr(&challenge));
+    lhs.equals(&rhs)
+}
+
+fn schnorr_challenge(pk: &G1Point, commitment: &G1Point, ctx: &[u8]) -> Scalar {
+    let mut transcript = Transcript::new(SCHNORR_DST);
+    transcript.append_message(b"ctx", ctx);
+    transcript.append_point(b"pk", pk);
+    transcript.append_point(b"commitment", commitment);
+    transcript.challenge_scalar(b"challenge")
+}
+
+#[cfg(all(test, not(feature = "failing-rng")))]
+mod tests {
+    use super::*;
+    
+    fn keypair(seed: &[u8]) -> (Scalar, G1Point) {
+        let sk = Scalar::random_from_seed(seed);
+        let pk = G1Point::generator().scalar_mul_fr(&sk);
+        (sk, pk)
+    }
+    
+    #[test]
+    fn proof_verifies() {
+        let (sk, pk) = keypair(b"schnorr key");
+        let proof = schnorr_prove(&sk, &pk, b"table 7").unwrap();
+        assert!(schnorr_verify(&pk, &proof, b"table 7"));
+    }
+    
+    #[test]
+    fn proof_is_bound_to_ctx_and_key() {
+        let (sk, pk) = keypair(b"schnorr key");
+        let (_, other_pk) = keypair(b"other key");
+        let proof = schnorr_prove(&sk, &pk, b"table 7").unwrap();
+        assert!(!schnorr_verify(&pk, &proof, b"table 8"));
+        assert!(!schnorr_verify(&other_pk, &proof, b"table 7"));
+    }
+    
+    #[test]
+    fn tampered_response_fails() {
+        let (sk, pk) = keypair(b"schnorr key");
+        let proof = schnorr_prove(&sk, &pk, b"table 7").unwrap();
+        let tampered = SchnorrProof::new(&proof.commitment(), &proof.response().add(&Scalar::from_u64(1)));
+        assert!(!schnorr_verify(&pk, &tampered, b"table 7"));
+    }
+}
@@ -5,9 +5,8 @@
 
 export interface PerformanceMetrics {
   operation: string;
+  iterations: number;
   wasm_time_us: number;
-  js_time_us: number;
-  speedup: number;
   memory_used_kb: number;
 }
 